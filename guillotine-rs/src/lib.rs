@@ -88,6 +88,17 @@ impl Evm {
         }
     }
 
+    /// Set a single storage slot for an address
+    pub fn set_storage(&mut self, address: [u8; 20], key: [u8; 32], value: [u8; 32]) -> Result<(), String> {
+        unsafe {
+            if guillotine_set_storage(self.handle, address.as_ptr(), key.as_ptr(), value.as_ptr()) {
+                Ok(())
+            } else {
+                Err(get_last_error())
+            }
+        }
+    }
+
     /// Execute a call
     pub fn execute(&mut self, params: &CallParams) -> Result<ExecutionResult, String> {
         unsafe {
@@ -115,6 +126,14 @@ impl Drop for Evm {
 unsafe impl Send for Evm {}
 unsafe impl Sync for Evm {}
 
+/// A log emitted during execution (LOG0-LOG4), as reported by the Zig EVM.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub address: [u8; 20],
+    pub topics: Vec<[u8; 32]>,
+    pub data: Vec<u8>,
+}
+
 /// Result of executing a transaction on the EVM
 #[derive(Debug, Clone)]
 pub struct ExecutionResult {
@@ -122,6 +141,9 @@ pub struct ExecutionResult {
     pub gas_used: u64,
     pub gas_left: u64,
     pub output: Vec<u8>,
+    pub logs: Vec<LogEntry>,
+    /// Gas attributed to each executed opcode, keyed by opcode byte.
+    pub gas_profile: Vec<(u8, u64)>,
 }
 
 // Helper functions
@@ -149,11 +171,35 @@ unsafe fn convert_result(result: &EvmResult) -> Result<ExecutionResult, String>
         Vec::new()
     };
 
+    let logs = if result.logs_len > 0 && !result.logs.is_null() {
+        slice::from_raw_parts(result.logs, result.logs_len)
+            .iter()
+            .map(|entry| LogEntry {
+                address: entry.address,
+                topics: entry.topics[..entry.topics_len as usize].to_vec(),
+                data: slice::from_raw_parts(entry.data, entry.data_len).to_vec(),
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let gas_profile = if result.gas_profile_len > 0 && !result.gas_profile.is_null() {
+        slice::from_raw_parts(result.gas_profile, result.gas_profile_len)
+            .iter()
+            .map(|entry| (entry.opcode, entry.gas))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     Ok(ExecutionResult {
         success: result.success,
         gas_used: 0, // Will be calculated from gas_left
         gas_left: result.gas_left,
         output,
+        logs,
+        gas_profile,
     })
 }
 
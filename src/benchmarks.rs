@@ -2,7 +2,8 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use sha3::{Keccak256, Digest};
-use crate::compiler::{ContractCompiler, CompiledContract};
+use crate::compiler::{ContractCompiler, CompiledContract, CompiledWasmModule};
+use crate::precompiles;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Benchmark {
@@ -14,6 +15,35 @@ pub struct Benchmark {
     pub bytecode: String,
     pub calldata: String,
     pub gas: u64,
+    /// Path to an `ExecutionContext` fixture (pre-funded accounts, access
+    /// list, caller/value/gas price) to seed before this benchmark runs,
+    /// instead of the two default empty synthetic accounts. Passed to each
+    /// `execute` subprocess by `run`; `DifferentialRunner` does not load it.
+    #[serde(default)]
+    pub context_fixture: Option<String>,
+    /// EIP-2930 access list to mark warm before this benchmark's call, for
+    /// backends that track per-call warm/cold storage access
+    /// (`crate::evm::AccessListEntry`, inlined here rather than requiring a
+    /// full `context_fixture` file for the common case of just warming a few
+    /// slots). Applied by `DifferentialRunner`; `run` uses it only to
+    /// prefetch fork state and does not pass it to its `execute` subprocesses.
+    #[serde(default)]
+    pub access_list: Vec<crate::evm::AccessListEntry>,
+    /// Known-good output (hex), when available, for `DifferentialRunner` to
+    /// assert against directly instead of only checking that executors agree
+    /// with each other. Used by the precompile benchmarks, where several
+    /// vectors have an output that's cheap to compute independently of any
+    /// EVM implementation (e.g. sha256, identity).
+    #[serde(default)]
+    pub expected_output: Option<String>,
+    /// Additional accounts (balance, nonce, code, storage) to seed before
+    /// this benchmark's call, reusing `crate::evm::PrestateAccount` so a
+    /// benchmark can measure a call against state that isn't always an
+    /// empty DB (e.g. a warm storage slot) without needing a full
+    /// `context_fixture` file. Applied by `DifferentialRunner`; `run` does not
+    /// pass it to its `execute` subprocesses.
+    #[serde(default)]
+    pub pre_state: HashMap<[u8; 20], crate::evm::PrestateAccount>,
 }
 
 pub fn get_function_selector(signature: &str) -> String {
@@ -37,6 +67,10 @@ pub fn get_evm_benchmarks(compiled_contracts: &HashMap<String, CompiledContract>
             bytecode: contract.bytecode.clone(),
             calldata: get_function_selector("Benchmark()"),
             gas: 30000000,
+            context_fixture: None,
+            access_list: Vec::new(),
+            expected_output: None,
+            pre_state: HashMap::new(),
         });
     }
     
@@ -50,6 +84,10 @@ pub fn get_evm_benchmarks(compiled_contracts: &HashMap<String, CompiledContract>
             bytecode: contract.bytecode.clone(),
             calldata: "0x30627b7c".to_string(), // Benchmark() function selector
             gas: 1000000000, // 1B gas
+            context_fixture: None,
+            access_list: Vec::new(),
+            expected_output: None,
+            pre_state: HashMap::new(),
         });
     }
     
@@ -63,6 +101,10 @@ pub fn get_evm_benchmarks(compiled_contracts: &HashMap<String, CompiledContract>
             bytecode: contract.bytecode.clone(),
             calldata: get_function_selector("Benchmark()"),
             gas: 30000000,
+            context_fixture: None,
+            access_list: Vec::new(),
+            expected_output: None,
+            pre_state: HashMap::new(),
         });
     }
     
@@ -76,6 +118,10 @@ pub fn get_evm_benchmarks(compiled_contracts: &HashMap<String, CompiledContract>
             bytecode: contract.bytecode.clone(),
             calldata: get_function_selector("Benchmark()"),
             gas: 30000000,
+            context_fixture: None,
+            access_list: Vec::new(),
+            expected_output: None,
+            pre_state: HashMap::new(),
         });
     }
     
@@ -89,9 +135,60 @@ pub fn get_evm_benchmarks(compiled_contracts: &HashMap<String, CompiledContract>
             bytecode: contract.bytecode.clone(),
             calldata: get_function_selector("Benchmark()"),
             gas: 30000000,
+            context_fixture: None,
+            access_list: Vec::new(),
+            expected_output: None,
+            pre_state: HashMap::new(),
         });
     }
     
+    // Precompile microbenchmarks: call each precompiled contract directly
+    // through a tiny dispatcher stub so their cost isn't hidden behind a
+    // full Solidity contract.
+    for vector in precompiles::all_vectors() {
+        let name = format!("precompile_{}", vector.label);
+        benchmarks.insert(name.clone(), Benchmark {
+            name,
+            description: format!("Precompile 0x{:02x} ({})", vector.address, vector.label),
+            category: "precompile".to_string(),
+            bench_type: "evm".to_string(),
+            bytecode: hex::encode(precompiles::dispatcher_bytecode(vector.address)),
+            calldata: format!("0x{}", hex::encode(&vector.calldata)),
+            gas: 30_000_000,
+            context_fixture: None,
+            access_list: Vec::new(),
+            expected_output: vector.expected_output.as_ref().map(|o| format!("0x{}", hex::encode(o))),
+            pre_state: HashMap::new(),
+        });
+    }
+
+    benchmarks
+}
+
+/// The WASM analogue of `get_evm_benchmarks`: one benchmark per compiled
+/// `benchmarks/wasm/<name>/` crate (see `ContractCompiler::compile_all_wasm`),
+/// run through `WasmExecutor` so the same algorithm can be timed as WASM
+/// against its Solidity equivalent from `get_evm_benchmarks`.
+pub fn get_wasm_benchmarks(compiled_wasm: &HashMap<String, CompiledWasmModule>) -> HashMap<String, Benchmark> {
+    let mut benchmarks = HashMap::new();
+
+    for module in compiled_wasm.values() {
+        let name = format!("wasm_{}", module.name);
+        benchmarks.insert(name.clone(), Benchmark {
+            name,
+            description: format!("WASM benchmark: {}", module.name),
+            category: "compute".to_string(),
+            bench_type: "wasm".to_string(),
+            bytecode: hex::encode(&module.module_bytes),
+            calldata: "0x".to_string(),
+            gas: 1_000_000_000,
+            context_fixture: None,
+            access_list: Vec::new(),
+            expected_output: None,
+            pre_state: HashMap::new(),
+        });
+    }
+
     benchmarks
 }
 
@@ -99,8 +196,9 @@ pub fn list_benchmarks(verbose: bool) -> Result<()> {
     // Compile contracts first
     let compiler = ContractCompiler::new()?;
     let compiled_contracts = compiler.compile_all()?;
-    let benchmarks = get_evm_benchmarks(&compiled_contracts);
-    
+    let mut benchmarks = get_evm_benchmarks(&compiled_contracts);
+    benchmarks.extend(get_wasm_benchmarks(&compiler.compile_all_wasm()?));
+
     if benchmarks.is_empty() {
         println!("No benchmarks available. Check that contracts compile successfully.");
         return Ok(());
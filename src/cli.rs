@@ -30,8 +30,38 @@ pub enum Commands {
         /// Gas limit
         #[arg(long)]
         gas: u64,
+
+        /// Execution mode for backends that support both an interpreter
+        /// and a JIT/AOT path
+        #[arg(long, value_enum, default_value = "interpreter")]
+        mode: crate::evm::ExecutionMode,
+
+        /// Dump an EIP-3155 per-opcode trace (or, for backends that record no
+        /// steps, the emitted logs and a per-opcode gas histogram) instead of
+        /// just the result summary
+        #[arg(long)]
+        trace: bool,
+
+        /// JSON-RPC URL to lazily fetch missing state from (revm only)
+        #[arg(long)]
+        fork_url: Option<String>,
+
+        /// Block number to pin forked state at
+        #[arg(long, requires = "fork_url")]
+        fork_block: Option<u64>,
+
+        /// Path to a `fork_db::prefetch_accounts` cache file to seed the
+        /// fork database from before falling back to RPC (internal; set by
+        /// `run` when prefetching ahead of a benchmark matrix)
+        #[arg(long, requires = "fork_url")]
+        fork_cache: Option<PathBuf>,
+
+        /// Path to an ExecutionContext fixture (pre-funded accounts, access
+        /// list, caller/value/gas price) to seed before executing
+        #[arg(long)]
+        context_fixture: Option<PathBuf>,
     },
-    
+
     /// Run benchmarks
     Run {
         /// Name of specific benchmark to run
@@ -68,8 +98,27 @@ pub enum Commands {
         /// Verbose output
         #[arg(short, long)]
         verbose: bool,
+
+        /// Execution mode for backends that support both an interpreter
+        /// and a JIT/AOT path
+        #[arg(long, value_enum, default_value = "interpreter")]
+        mode: crate::evm::ExecutionMode,
+
+        /// Dump an EIP-3155 per-opcode trace (or, for backends that record no
+        /// steps, the emitted logs and a per-opcode gas histogram) instead of
+        /// timing
+        #[arg(long)]
+        trace: bool,
+
+        /// JSON-RPC URL to lazily fetch missing state from (revm only)
+        #[arg(long)]
+        fork_url: Option<String>,
+
+        /// Block number to pin forked state at
+        #[arg(long, requires = "fork_url")]
+        fork_block: Option<u64>,
     },
-    
+
     /// List available benchmarks
     List {
         /// Show detailed information
@@ -81,24 +130,101 @@ pub enum Commands {
     Compare {
         /// EVM implementations to compare
         evms: Vec<String>,
-        
+
         /// Specific benchmark to compare
         #[arg(short, long)]
         benchmark: Option<String>,
-        
+
         /// Output file for comparison
         #[arg(short, long)]
         output: Option<PathBuf>,
     },
+
+    /// Run bytecode through multiple EVM implementations and assert that
+    /// success, gas used, and output agree, reporting any divergence
+    Diff {
+        /// EVM implementations to cross-check (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        evms: Vec<String>,
+
+        /// Bytecode to execute (hex string)
+        #[arg(long)]
+        bytecode: String,
+
+        /// Calldata (hex string)
+        #[arg(long)]
+        calldata: String,
+
+        /// Gas limit
+        #[arg(long)]
+        gas: u64,
+
+        /// Execution mode for backends that support both an interpreter
+        /// and a JIT/AOT path
+        #[arg(long, value_enum, default_value = "interpreter")]
+        mode: crate::evm::ExecutionMode,
+    },
+
+    /// Run the whole benchmark suite through multiple EVM implementations
+    /// as a correctness oracle rather than a speed comparison
+    DiffBenchmarks {
+        /// EVM implementations to cross-check (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        evms: Vec<String>,
+
+        /// Require gas_used to match exactly instead of only comparing
+        /// success/output (different EVMs may legitimately disagree on
+        /// refund accounting)
+        #[arg(long)]
+        exact_gas: bool,
+    },
+
+    /// Run a GeneralStateTests JSON fixture against an EVM implementation
+    RunStateTests {
+        /// Path to the GeneralStateTests JSON file
+        path: PathBuf,
+
+        /// Fork name to select from the `post` section (e.g. "Cancun")
+        #[arg(short, long)]
+        fork: String,
+
+        /// EVM implementation to use (guillotine or revm; only guillotine
+        /// can seed a fixture's pre-state, so revm is rejected when run)
+        #[arg(short, long, default_value = "guillotine")]
+        evm: String,
+    },
 }
 
 impl Cli {
     pub fn execute(self) -> Result<()> {
         match self.command {
-            Commands::Execute { evm, bytecode, calldata, gas } => {
-                crate::evm::execute_bytecode(&evm, &bytecode, &calldata, gas)?;
+            Commands::Execute { evm, bytecode, calldata, gas, mode, trace, fork_url, fork_block, fork_cache, context_fixture } => {
+                let mut executor: Box<dyn crate::evm::EvmExecutor> = if let Some(rpc_url) = fork_url {
+                    anyhow::ensure!(evm == "revm", "--fork-url is only supported with --evm revm");
+                    let block = fork_block.ok_or_else(|| anyhow::anyhow!("--fork-block is required with --fork-url"))?;
+                    match fork_cache {
+                        Some(cache_path) => Box::new(crate::evms::revm::RevmExecutor::with_fork_and_cache(rpc_url, block, cache_path)?),
+                        None => Box::new(crate::evms::revm::RevmExecutor::with_fork(rpc_url, block)?),
+                    }
+                } else {
+                    crate::evm::make_executor(&evm)?
+                };
+
+                if let Some(fixture_path) = context_fixture {
+                    let bytecode_bytes = crate::evm::decode_hex(&bytecode)?;
+                    let calldata_bytes = crate::evm::decode_hex(&calldata)?;
+                    let ctx = crate::context::load_execution_context(&fixture_path, bytecode_bytes, calldata_bytes, gas)?;
+                    let result = executor.execute_with_context(ctx)?;
+                    println!("Success: {}", result.success);
+                    println!("Gas used: {}", result.gas_used);
+                    println!("Output: 0x{}", hex::encode(&result.output));
+                } else if trace {
+                    crate::evm::run_executor(executor.as_mut(), &bytecode, &calldata, gas, mode, true)?;
+                } else {
+                    crate::evm::run_executor(executor.as_mut(), &bytecode, &calldata, gas, mode, false)?;
+                }
             }
-            Commands::Run { 
+            Commands::Run {
                 benchmark,
                 iterations,
                 warmup,
@@ -108,7 +234,17 @@ impl Cli {
                 output,
                 export_json,
                 verbose,
+                mode,
+                trace,
+                fork_url,
+                fork_block,
             } => {
+                if fork_url.is_some() {
+                    anyhow::ensure!(
+                        evm.as_deref() == Some("revm"),
+                        "--fork-url is only supported with --evm revm"
+                    );
+                }
                 crate::runner::run_benchmarks(
                     benchmark,
                     iterations,
@@ -119,6 +255,10 @@ impl Cli {
                     output,
                     export_json,
                     verbose,
+                    mode,
+                    trace,
+                    fork_url,
+                    fork_block,
                 )?;
             }
             Commands::List { verbose } => {
@@ -127,6 +267,79 @@ impl Cli {
             Commands::Compare { evms, benchmark, output } => {
                 crate::runner::compare_evms(evms, benchmark, output)?;
             }
+            Commands::Diff { evms, bytecode, calldata, gas, mode } => {
+                anyhow::ensure!(evms.len() >= 2, "--evms needs at least two implementations to diff");
+                let report = crate::differential::run_diff(&evms, &bytecode, &calldata, gas, mode)?;
+                if report.agree {
+                    println!("✅ All {} implementations agree", evms.len());
+                } else {
+                    println!("❌ Divergence detected across {:?}:", evms);
+                    for mismatch in &report.mismatches {
+                        println!("  {}:", mismatch.field);
+                        for (name, value) in &mismatch.values {
+                            println!("    {}: {}", name, value);
+                        }
+                    }
+                    anyhow::bail!("EVM implementations diverged");
+                }
+            }
+            Commands::DiffBenchmarks { evms, exact_gas } => {
+                anyhow::ensure!(evms.len() >= 2, "--evms needs at least two implementations to diff");
+
+                let compiler = crate::compiler::ContractCompiler::new()?;
+                let compiled_contracts = compiler.compile_all()?;
+                let mut benchmarks = crate::benchmarks::get_evm_benchmarks(&compiled_contracts);
+                benchmarks.extend(crate::benchmarks::get_wasm_benchmarks(&compiler.compile_all_wasm()?));
+
+                let executors = evms.iter().map(|name| crate::evm::make_executor(name)).collect::<Result<Vec<_>>>()?;
+                let gas_mode = if exact_gas {
+                    crate::differential::GasEqualityMode::Exact
+                } else {
+                    crate::differential::GasEqualityMode::SuccessOnly
+                };
+                let mut runner = crate::differential::DifferentialRunner::new(executors, gas_mode);
+                let diverged = runner.run_all(&benchmarks)?;
+
+                if diverged.is_empty() {
+                    println!("✅ {} implementations agree on all {} benchmarks", evms.len(), benchmarks.len());
+                } else {
+                    println!("❌ Divergence on {}/{} benchmarks:", diverged.len(), benchmarks.len());
+                    for (name, report) in &diverged {
+                        println!("\n  {}:", name);
+                        for mismatch in &report.mismatches {
+                            println!("    {}:", mismatch.field);
+                            for (evm_name, value) in &mismatch.values {
+                                println!("      {}: {}", evm_name, value);
+                            }
+                        }
+                    }
+                    anyhow::bail!("EVM implementations diverged on {} benchmark(s)", diverged.len());
+                }
+            }
+            Commands::RunStateTests { path, fork, evm } => {
+                let tests = crate::state_tests::load_state_tests(&path)?;
+                let mut executor: Box<dyn crate::evm::EvmExecutor> = match evm.as_str() {
+                    "guillotine" => Box::new(crate::evms::guillotine::GuillotineExecutor::new()?),
+                    "revm" => Box::new(crate::evms::revm::RevmExecutor::new()?),
+                    other => anyhow::bail!("Unknown EVM implementation: {}", other),
+                };
+
+                for (name, test) in &tests {
+                    println!("Running state test: {}", name);
+                    let cases = crate::state_tests::run_state_test(executor.as_mut(), test, &fork)?;
+                    for case in cases {
+                        println!(
+                            "  [data={} gas={} value={}] success={} gas_used={} output=0x{}",
+                            case.indexes.data, case.indexes.gas, case.indexes.value,
+                            case.success, case.gas_used, hex::encode(&case.output),
+                        );
+                        println!(
+                            "    expected post hash={} logs hash={} (not verified: no post-state trie/log-RLP implementation here)",
+                            case.expected_post_hash, case.expected_logs_hash,
+                        );
+                    }
+                }
+            }
         }
         Ok(())
     }
@@ -5,6 +5,7 @@ use foundry_compilers::{
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::process::Command;
 
 pub struct ContractCompiler {
     project_root: PathBuf,
@@ -145,6 +146,76 @@ impl ContractCompiler {
         let contracts = self.compile_all()?;
         Ok(contracts.get(name).cloned())
     }
+
+    /// Compile the WASM benchmark crates under `benchmarks/wasm/` to
+    /// `wasm32-unknown-unknown` and collect the resulting modules.
+    ///
+    /// Each benchmark is its own crate directory under `benchmarks/wasm/<name>/`
+    /// so they can be built independently with `cargo build --release`.
+    pub fn compile_all_wasm(&self) -> Result<HashMap<String, CompiledWasmModule>> {
+        println!("Compiling WASM contracts...");
+
+        let mut compiled_modules = HashMap::new();
+        let wasm_dir = self.contracts_dir.join("wasm");
+
+        if !wasm_dir.exists() {
+            return Ok(compiled_modules);
+        }
+
+        for entry in fs::read_dir(&wasm_dir)? {
+            let entry = entry?;
+            let crate_dir = entry.path();
+            if !crate_dir.is_dir() {
+                continue;
+            }
+
+            let crate_name = crate_dir
+                .file_name()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default()
+                .to_string();
+
+            println!("  Building {} for wasm32-unknown-unknown...", crate_name);
+
+            let status = Command::new("cargo")
+                .arg("build")
+                .arg("--release")
+                .arg("--target")
+                .arg("wasm32-unknown-unknown")
+                .current_dir(&crate_dir)
+                .status()?;
+
+            if !status.success() {
+                println!("  ⚠ {} failed to compile to WASM", crate_name);
+                continue;
+            }
+
+            let wasm_file = crate_dir
+                .join("target")
+                .join("wasm32-unknown-unknown")
+                .join("release")
+                .join(format!("{}.wasm", crate_name.replace('-', "_")));
+
+            if !wasm_file.exists() {
+                println!("  ⚠ {} produced no .wasm artifact at {}", crate_name, wasm_file.display());
+                continue;
+            }
+
+            let module_bytes = fs::read(&wasm_file)?;
+            compiled_modules.insert(
+                crate_name.clone(),
+                CompiledWasmModule {
+                    name: crate_name.clone(),
+                    module_bytes,
+                    path: wasm_file.to_string_lossy().to_string(),
+                },
+            );
+
+            println!("  ✓ Compiled {} to WASM", crate_name);
+        }
+
+        Ok(compiled_modules)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -152,4 +223,12 @@ pub struct CompiledContract {
     pub name: String,
     pub bytecode: String,
     pub path: String,
+}
+
+/// A compiled WASM benchmark artifact, the WASM analogue of `CompiledContract`.
+#[derive(Debug, Clone)]
+pub struct CompiledWasmModule {
+    pub name: String,
+    pub module_bytes: Vec<u8>,
+    pub path: String,
 }
\ No newline at end of file
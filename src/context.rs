@@ -0,0 +1,116 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use crate::evm::{AccessListEntry, ExecutionContext, PrestateAccount};
+use crate::hex_utils::{decode_hex, parse_address, parse_u256_be, parse_u64_hex};
+
+/// On-disk shape of an `ExecutionContext` fixture: everything but the
+/// bytecode/calldata/gas limit, which already live on the `Benchmark` (or are
+/// passed on the `execute` CLI command) that references this fixture.
+#[derive(Debug, Default, Deserialize)]
+struct ContextFixture {
+    #[serde(default)]
+    caller: Option<String>,
+    #[serde(default)]
+    value: Option<String>,
+    #[serde(default)]
+    gas_price: Option<String>,
+    #[serde(default)]
+    prestate: HashMap<String, FixtureAccount>,
+    #[serde(default)]
+    access_list: Vec<FixtureAccessListEntry>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FixtureAccount {
+    #[serde(default)]
+    balance: Option<String>,
+    #[serde(default)]
+    nonce: Option<String>,
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    storage: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FixtureAccessListEntry {
+    address: String,
+    #[serde(default)]
+    storage_keys: Vec<String>,
+}
+
+/// Read just the access-list entries (address plus its storage keys) out of
+/// a fixture at `path`, without requiring the bytecode/calldata/gas
+/// `load_execution_context` needs. Used to prefetch fork state (accounts and
+/// the specific storage slots they'll be read at) for a batch of benchmarks
+/// before any of them run.
+pub fn load_access_list_entries(path: &Path) -> Result<Vec<AccessListEntry>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read execution context fixture: {}", path.display()))?;
+    let fixture: ContextFixture = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse execution context fixture: {}", path.display()))?;
+
+    fixture.access_list.iter().map(|entry| {
+        let address = parse_address(&entry.address)?;
+        let keys = entry.storage_keys.iter().map(|k| parse_u256_be(k)).collect::<Result<Vec<_>>>()?;
+        Ok((address, keys))
+    }).collect()
+}
+
+/// Load an `ExecutionContext` fixture from `path`, filling in `bytecode`,
+/// `calldata` and `gas_limit` from the caller (the `Benchmark` or `execute`
+/// CLI invocation that referenced this fixture) since the fixture only
+/// describes the surrounding context, not the call itself.
+pub fn load_execution_context(
+    path: &Path,
+    bytecode: Vec<u8>,
+    calldata: Vec<u8>,
+    gas_limit: u64,
+) -> Result<ExecutionContext> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read execution context fixture: {}", path.display()))?;
+    let fixture: ContextFixture = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse execution context fixture: {}", path.display()))?;
+
+    let caller = fixture.caller.as_deref().map(parse_address).transpose()?
+        .unwrap_or([0x01; 20]);
+    let value = fixture.value.as_deref().map(parse_u256_be).transpose()?
+        .unwrap_or([0u8; 32]);
+    let gas_price = fixture.gas_price.as_deref().map(parse_u64_hex).transpose()?
+        .unwrap_or(1_000_000_000);
+
+    let mut prestate = HashMap::new();
+    for (addr, account) in &fixture.prestate {
+        let address = parse_address(addr)?;
+        let mut storage = HashMap::new();
+        for (key, val) in &account.storage {
+            storage.insert(parse_u256_be(key)?, parse_u256_be(val)?);
+        }
+        prestate.insert(address, PrestateAccount {
+            balance: account.balance.as_deref().map(parse_u256_be).transpose()?.unwrap_or_default(),
+            nonce: account.nonce.as_deref().map(parse_u64_hex).transpose()?.unwrap_or_default(),
+            code: account.code.as_deref().map(decode_hex).transpose()?.unwrap_or_default(),
+            storage,
+        });
+    }
+
+    let mut access_list: Vec<AccessListEntry> = Vec::new();
+    for entry in &fixture.access_list {
+        let address = parse_address(&entry.address)?;
+        let keys = entry.storage_keys.iter().map(|k| parse_u256_be(k)).collect::<Result<Vec<_>>>()?;
+        access_list.push((address, keys));
+    }
+
+    Ok(ExecutionContext {
+        bytecode,
+        calldata,
+        gas_limit,
+        caller,
+        value,
+        gas_price,
+        prestate,
+        access_list,
+    })
+}
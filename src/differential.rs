@@ -0,0 +1,251 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use crate::benchmarks::Benchmark;
+use crate::evm::{EvmExecutor, EvmResult, ExecutionMode};
+
+/// A single field (`success`, `gas_used`, or `output`) on which two or more
+/// executors disagreed for the same input, or `expected_output` when at least
+/// one executor's output differs from a known-good value.
+#[derive(Debug)]
+pub struct FieldMismatch {
+    pub field: String,
+    /// `(evm name, formatted value)` for every executor that ran, in order.
+    /// For `expected_output`, only the executors whose output differs from
+    /// the expected value, followed by `("expected", value)`.
+    pub values: Vec<(String, String)>,
+}
+
+/// Outcome of running one input through a set of executors and comparing
+/// their `EvmResult`s.
+#[derive(Debug)]
+pub struct DiffReport {
+    pub agree: bool,
+    pub mismatches: Vec<FieldMismatch>,
+}
+
+/// How strictly `gas_used` is compared across executors. Different EVM
+/// implementations can legitimately disagree on refund accounting, so a
+/// caller that only cares about consensus-level correctness can relax this
+/// to `SuccessOnly` instead of failing on every minor gas delta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasEqualityMode {
+    /// `gas_used` must match exactly across all executors.
+    Exact,
+    /// Only `success` and `output` are compared; `gas_used` is ignored.
+    SuccessOnly,
+}
+
+/// Compare a set of `(name, EvmResult)` pairs and report any field on which
+/// they disagree, honoring `gas_mode` for how strictly gas is checked. When
+/// `expected_output` is given, every executor's output is also checked
+/// against it directly, rather than only against each other, so a case
+/// where every executor is *consistently* wrong doesn't read as agreement.
+fn compare_results(
+    results: &[(String, EvmResult)],
+    gas_mode: GasEqualityMode,
+    expected_output: Option<&[u8]>,
+) -> DiffReport {
+    let mut mismatches = Vec::new();
+
+    if let Some(expected) = expected_output {
+        let mut values: Vec<(String, String)> = results
+            .iter()
+            .filter(|(_, r)| r.output != expected)
+            .map(|(name, r)| (name.clone(), format!("0x{}", hex::encode(&r.output))))
+            .collect();
+        if !values.is_empty() {
+            values.push(("expected".to_string(), format!("0x{}", hex::encode(expected))));
+            mismatches.push(FieldMismatch { field: "expected_output".to_string(), values });
+        }
+    }
+
+    if let Some((_, first)) = results.first() {
+        let successes: Vec<(String, String)> = results
+            .iter()
+            .map(|(name, r)| (name.clone(), r.success.to_string()))
+            .collect();
+        if results.iter().any(|(_, r)| r.success != first.success) {
+            mismatches.push(FieldMismatch { field: "success".to_string(), values: successes });
+        }
+
+        if gas_mode == GasEqualityMode::Exact {
+            let gas_used: Vec<(String, String)> = results
+                .iter()
+                .map(|(name, r)| (name.clone(), r.gas_used.to_string()))
+                .collect();
+            if results.iter().any(|(_, r)| r.gas_used != first.gas_used) {
+                mismatches.push(FieldMismatch { field: "gas_used".to_string(), values: gas_used });
+            }
+        }
+
+        let output: Vec<(String, String)> = results
+            .iter()
+            .map(|(name, r)| (name.clone(), format!("0x{}", hex::encode(&r.output))))
+            .collect();
+        if results.iter().any(|(_, r)| r.output != first.output) {
+            mismatches.push(FieldMismatch { field: "output".to_string(), values: output });
+        }
+    }
+
+    DiffReport { agree: mismatches.is_empty(), mismatches }
+}
+
+/// Run `bytecode`/`calldata`/`gas_limit` through every named backend in
+/// `evms` and assert that `success`, `gas_used`, and `output` agree across
+/// all of them, so a single input can be spot-checked for cross-client
+/// conformance rather than only timed.
+pub fn run_diff(
+    evms: &[String],
+    bytecode: &str,
+    calldata: &str,
+    gas_limit: u64,
+    mode: ExecutionMode,
+) -> Result<DiffReport> {
+    let bytecode_bytes = crate::evm::decode_hex(bytecode)?;
+    let calldata_bytes = crate::evm::decode_hex(calldata)?;
+
+    let mut results = Vec::new();
+    for evm_name in evms {
+        let mut executor = crate::evm::make_executor(evm_name)?;
+        let result = executor.execute_with_mode(bytecode_bytes.clone(), calldata_bytes.clone(), gas_limit, mode)?;
+        results.push((evm_name.clone(), result));
+    }
+
+    Ok(compare_results(&results, GasEqualityMode::Exact, None))
+}
+
+/// Drives a fixed set of `EvmExecutor`s across the whole benchmark suite as
+/// a correctness oracle: every benchmark's `(bytecode, calldata, gas)` is run
+/// through every executor and the results are asserted to agree, rather than
+/// only timed. This is how `get_evm_benchmarks` catches EVM implementation
+/// bugs rather than just measuring them.
+pub struct DifferentialRunner {
+    executors: Vec<Box<dyn EvmExecutor>>,
+    gas_mode: GasEqualityMode,
+}
+
+impl DifferentialRunner {
+    pub fn new(executors: Vec<Box<dyn EvmExecutor>>, gas_mode: GasEqualityMode) -> Self {
+        Self { executors, gas_mode }
+    }
+
+    /// Run every benchmark in `benchmarks` through every executor, returning
+    /// only the ones where at least one executor diverged.
+    pub fn run_all(&mut self, benchmarks: &HashMap<String, Benchmark>) -> Result<Vec<(String, DiffReport)>> {
+        let mut diverged = Vec::new();
+
+        for (name, benchmark) in benchmarks {
+            let bytecode = crate::evm::decode_hex(&benchmark.bytecode)?;
+            let calldata = crate::evm::decode_hex(&benchmark.calldata)?;
+
+            let mut results = Vec::new();
+            for executor in self.executors.iter_mut() {
+                let evm_name = executor.name().to_string();
+                // `pre_state` and `access_list` aren't composable through the
+                // trait's default methods (each seeds and runs in one shot),
+                // so a benchmark carrying pre-state takes priority; access
+                // lists are only meaningful for the common empty-pre-state case.
+                let result = if benchmark.pre_state.is_empty() {
+                    executor.execute_with_access_list(
+                        bytecode.clone(),
+                        calldata.clone(),
+                        benchmark.gas,
+                        &benchmark.access_list,
+                    )?
+                } else {
+                    executor.execute_with_prestate(
+                        bytecode.clone(),
+                        calldata.clone(),
+                        benchmark.gas,
+                        &benchmark.pre_state,
+                    )?
+                };
+                results.push((evm_name, result));
+            }
+
+            let expected_output = benchmark
+                .expected_output
+                .as_deref()
+                .map(crate::evm::decode_hex)
+                .transpose()?;
+            let report = compare_results(&results, self.gas_mode, expected_output.as_deref());
+            if !report.agree {
+                diverged.push((name.clone(), report));
+            }
+        }
+
+        Ok(diverged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evm::ExecStatus;
+
+    fn result(success: bool, gas_used: u64, output: Vec<u8>) -> EvmResult {
+        EvmResult {
+            success,
+            gas_used,
+            refunded: 0,
+            status: if success { ExecStatus::Success } else { ExecStatus::Revert },
+            output,
+            logs: Vec::new(),
+            gas_profile: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn agrees_when_all_fields_match() {
+        let results = vec![
+            ("a".to_string(), result(true, 100, vec![0x2a])),
+            ("b".to_string(), result(true, 100, vec![0x2a])),
+        ];
+        let report = compare_results(&results, GasEqualityMode::Exact, None);
+        assert!(report.agree);
+        assert!(report.mismatches.is_empty());
+    }
+
+    #[test]
+    fn reports_success_mismatch() {
+        let results = vec![
+            ("a".to_string(), result(true, 100, vec![])),
+            ("b".to_string(), result(false, 100, vec![])),
+        ];
+        let report = compare_results(&results, GasEqualityMode::Exact, None);
+        assert!(!report.agree);
+        assert!(report.mismatches.iter().any(|m| m.field == "success"));
+    }
+
+    #[test]
+    fn success_only_mode_ignores_gas_used_mismatch() {
+        let results = vec![
+            ("a".to_string(), result(true, 100, vec![0x2a])),
+            ("b".to_string(), result(true, 200, vec![0x2a])),
+        ];
+        let report = compare_results(&results, GasEqualityMode::SuccessOnly, None);
+        assert!(report.agree);
+    }
+
+    #[test]
+    fn exact_mode_reports_gas_used_mismatch() {
+        let results = vec![
+            ("a".to_string(), result(true, 100, vec![0x2a])),
+            ("b".to_string(), result(true, 200, vec![0x2a])),
+        ];
+        let report = compare_results(&results, GasEqualityMode::Exact, None);
+        assert!(!report.agree);
+        assert!(report.mismatches.iter().any(|m| m.field == "gas_used"));
+    }
+
+    #[test]
+    fn expected_output_flags_executors_that_agree_but_are_wrong() {
+        let results = vec![
+            ("a".to_string(), result(true, 100, vec![0xff])),
+            ("b".to_string(), result(true, 100, vec![0xff])),
+        ];
+        let report = compare_results(&results, GasEqualityMode::Exact, Some(&[0x2a]));
+        assert!(!report.agree);
+        assert!(report.mismatches.iter().any(|m| m.field == "expected_output"));
+    }
+}
@@ -15,6 +15,7 @@ pub fn show_results(results: &HashMap<String, HashMap<String, BenchmarkResult>>)
         let mut table = Table::new();
         table.set_header(vec![
             Cell::new("EVM").add_attribute(Attribute::Bold),
+            Cell::new("Mode").add_attribute(Attribute::Bold),
             Cell::new("Mean (s)").add_attribute(Attribute::Bold),
             Cell::new("Std Dev").add_attribute(Attribute::Bold),
             Cell::new("Min (s)").add_attribute(Attribute::Bold),
@@ -39,6 +40,7 @@ pub fn show_results(results: &HashMap<String, HashMap<String, BenchmarkResult>>)
                 } else {
                     Cell::new(evm_name)
                 },
+                Cell::new(&result.mode),
                 Cell::new(format!("{:.4}", result.mean)),
                 Cell::new(format!("{:.4}", result.stddev)),
                 Cell::new(format!("{:.4}", result.min)),
@@ -1,11 +1,128 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// An emitted event log, as captured from backends that support it.
+#[derive(Debug, Clone)]
+pub struct Log {
+    pub address: [u8; 20],
+    pub topics: Vec<[u8; 32]>,
+    pub data: Vec<u8>,
+}
+
+/// Gas attributed to a single opcode across an execution, keyed by opcode
+/// byte. Lets `--trace` explain *why* one backend is slower by showing a
+/// divergent gas/opcode mix rather than only a wall-clock delta.
+pub type GasProfile = Vec<(u8, u64)>;
+
+/// How a call ended: a plain `success: bool` can't distinguish a revert
+/// (which returns data and consumes no extra gas beyond what was spent) from
+/// a halt (out-of-gas, invalid opcode, stack over/underflow, ...), which
+/// matters for benchmarks like `erc20_transfer_bench` that need to assert
+/// *why* a call didn't succeed, not just that it didn't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecStatus {
+    Success,
+    Revert,
+    Halt { reason: String },
+}
 
 #[derive(Debug)]
 pub struct EvmResult {
     pub success: bool,
     pub gas_used: u64,
+    /// Gas refunded (e.g. SSTORE clears), already excluded from `gas_used`.
+    pub refunded: u64,
     pub output: Vec<u8>,
-    pub logs: Vec<String>,
+    pub logs: Vec<Log>,
+    pub gas_profile: GasProfile,
+    pub status: ExecStatus,
+}
+
+/// One EIP-3155 trace step: program counter, opcode, remaining gas, gas cost
+/// of this step, call depth, and the stack (as hex words), so traces from
+/// different backends are directly line-diffable against each other.
+#[derive(Debug, Clone)]
+pub struct TraceStep {
+    pub pc: u64,
+    pub op: u8,
+    pub gas: u64,
+    pub gas_cost: u64,
+    pub depth: u64,
+    pub stack: Vec<[u8; 32]>,
+    pub mem_size: Option<u64>,
+}
+
+/// The kind of bytecode a backend executes, so the runner can route a
+/// benchmark (EVM opcodes vs. a WASM module) to a compatible backend only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BytecodeKind {
+    Evm,
+    Wasm,
+}
+
+/// What a backend can do, queried by the runner so it can skip incompatible
+/// benchmarks and explain why, rather than silently returning empty results
+/// (e.g. empty logs from a backend that doesn't capture them).
+#[derive(Debug, Clone, Copy)]
+pub struct Capabilities {
+    pub bytecode_kind: BytecodeKind,
+    pub supports_state: bool,
+    pub supports_logs: bool,
+    pub supports_tracing: bool,
+    pub has_jit: bool,
+}
+
+/// Execution strategy for backends that support both a plain interpreter
+/// and a JIT/AOT compiled path, so users can see compile-time-vs-throughput
+/// tradeoffs rather than only ever hitting one fixed mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExecutionMode {
+    Interpreter,
+    Jit,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            bytecode_kind: BytecodeKind::Evm,
+            supports_state: false,
+            supports_logs: false,
+            supports_tracing: false,
+            has_jit: false,
+        }
+    }
+}
+
+/// One pre-funded account in an `ExecutionContext`'s pre-state: balance,
+/// nonce, code and storage, mirroring the `pre` section of a
+/// `GeneralStateTests` fixture but scoped to a single benchmark.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PrestateAccount {
+    pub balance: [u8; 32],
+    pub nonce: u64,
+    pub code: Vec<u8>,
+    pub storage: HashMap<[u8; 32], [u8; 32]>,
+}
+
+/// An EIP-2930 access list entry: an address plus the storage keys within it
+/// that should be marked warm before the call.
+pub type AccessListEntry = ([u8; 20], Vec<[u8; 32]>);
+
+/// Full input to a call, replacing the bare `(bytecode, calldata, gas_limit)`
+/// triple so fixtures can model a funded caller, pre-warmed accounts with
+/// nonzero storage, and an access list instead of always starting from the
+/// two empty synthetic accounts `execute` seeds by default.
+#[derive(Debug, Clone)]
+pub struct ExecutionContext {
+    pub bytecode: Vec<u8>,
+    pub calldata: Vec<u8>,
+    pub gas_limit: u64,
+    pub caller: [u8; 20],
+    pub value: [u8; 32],
+    pub gas_price: u64,
+    pub prestate: HashMap<[u8; 20], PrestateAccount>,
+    pub access_list: Vec<AccessListEntry>,
 }
 
 pub trait EvmExecutor {
@@ -15,6 +132,224 @@ pub trait EvmExecutor {
         calldata: Vec<u8>,
         gas_limit: u64,
     ) -> Result<EvmResult>;
-    
+
     fn name(&self) -> &str;
+
+    /// Set an account's balance before execution. Used to seed pre-state
+    /// (e.g. from a `GeneralStateTests` fixture) ahead of a call.
+    fn set_balance(&mut self, _address: [u8; 20], _balance: [u8; 32]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Set an account's code before execution.
+    fn set_code(&mut self, _address: [u8; 20], _code: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Set a single storage slot for an address before execution. Backends
+    /// that don't support pre-seeding storage can ignore it.
+    fn set_storage(&mut self, _address: [u8; 20], _key: [u8; 32], _value: [u8; 32]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Describe what this backend supports, so callers can skip incompatible
+    /// benchmarks instead of silently getting empty logs/traces back.
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+
+    /// Execute with an explicit interpreter-vs-JIT mode. The default just
+    /// runs `execute` for `Interpreter` and reports `Jit` as unsupported,
+    /// so backends without a JIT path don't silently ignore the flag.
+    fn execute_with_mode(
+        &mut self,
+        bytecode: Vec<u8>,
+        calldata: Vec<u8>,
+        gas_limit: u64,
+        mode: ExecutionMode,
+    ) -> Result<EvmResult> {
+        match mode {
+            ExecutionMode::Interpreter => self.execute(bytecode, calldata, gas_limit),
+            ExecutionMode::Jit => Err(anyhow::anyhow!(
+                "mode unsupported: {} has no JIT/AOT path",
+                self.name()
+            )),
+        }
+    }
+
+    /// Execute a full `ExecutionContext`. The default seeds `prestate` via
+    /// `set_balance`/`set_code`/`set_storage` and then calls `execute`, which
+    /// is enough for any backend that already implements those; `caller`,
+    /// `value`, `gas_price` and `access_list` are honored only by backends
+    /// that override this (see `RevmExecutor`), since applying them generally
+    /// requires reaching into the backend's transaction-building code.
+    fn execute_with_context(&mut self, ctx: ExecutionContext) -> Result<EvmResult> {
+        for (address, account) in &ctx.prestate {
+            self.set_balance(*address, account.balance)?;
+            self.set_code(*address, &account.code)?;
+            for (key, value) in &account.storage {
+                self.set_storage(*address, *key, *value)?;
+            }
+        }
+        self.execute(ctx.bytecode, ctx.calldata, ctx.gas_limit)
+    }
+
+    /// Execute against `bytecode`/`calldata` with additional accounts
+    /// (balance, code, storage) pre-seeded beyond the benchmark's own
+    /// contract and caller, so a call can touch state that's already
+    /// initialized (e.g. a nonzero storage slot, for measuring the
+    /// warm-SSTORE/refund path) instead of always starting from an empty DB.
+    /// The default seeds via `set_balance`/`set_code`/`set_storage` and then
+    /// calls `execute`, mirroring `execute_with_context`'s default; backends
+    /// that need a single atomic seed (see `RevmExecutor`) override this.
+    fn execute_with_prestate(
+        &mut self,
+        bytecode: Vec<u8>,
+        calldata: Vec<u8>,
+        gas_limit: u64,
+        pre_state: &HashMap<[u8; 20], PrestateAccount>,
+    ) -> Result<EvmResult> {
+        for (address, account) in pre_state {
+            self.set_balance(*address, account.balance)?;
+            self.set_code(*address, &account.code)?;
+            for (key, value) in &account.storage {
+                self.set_storage(*address, *key, *value)?;
+            }
+        }
+        self.execute(bytecode, calldata, gas_limit)
+    }
+
+    /// Execute with an EIP-2930 access list pre-warmed, so gas accounting
+    /// reflects EIP-2929 warm/cold semantics. The default ignores
+    /// `access_list` and just runs `execute`, so backends that don't track
+    /// per-call warm/cold access still produce a valid `EvmResult` rather
+    /// than failing a benchmark that carries one (see `GuillotineExecutor`
+    /// for the one backend that currently honors it).
+    fn execute_with_access_list(
+        &mut self,
+        bytecode: Vec<u8>,
+        calldata: Vec<u8>,
+        gas_limit: u64,
+        access_list: &[AccessListEntry],
+    ) -> Result<EvmResult> {
+        let _ = access_list;
+        self.execute(bytecode, calldata, gas_limit)
+    }
+
+    /// Execute with full EIP-3155 per-opcode tracing. The default just runs
+    /// `execute` and returns no steps, so backends without instrumentation
+    /// still produce a valid `EvmResult` rather than failing `--trace`.
+    fn execute_traced(
+        &mut self,
+        bytecode: Vec<u8>,
+        calldata: Vec<u8>,
+        gas_limit: u64,
+    ) -> Result<(EvmResult, Vec<TraceStep>)> {
+        let result = self.execute(bytecode, calldata, gas_limit)?;
+        Ok((result, Vec::new()))
+    }
+
+    /// Execute a call against an already-seeded contract `to`, rather than
+    /// the fixed synthetic contract address `execute` always writes
+    /// `bytecode` to. Needed by `state_tests::run_state_test`, where
+    /// pre-state is seeded at the fixture's real `pre` addresses via
+    /// `set_code`/`set_storage` and a `SLOAD` must see it there, instead of
+    /// `execute` running the same code at an address whose storage was never
+    /// seeded. The default reports this as unsupported rather than silently
+    /// executing at the wrong address and returning a result that looks
+    /// valid but isn't; only backends with a transaction-builder surface
+    /// that accepts an explicit `to` (see `GuillotineExecutor`) can honor it.
+    fn execute_at(
+        &mut self,
+        _to: [u8; 20],
+        _calldata: Vec<u8>,
+        _gas_limit: u64,
+    ) -> Result<EvmResult> {
+        Err(anyhow::anyhow!(
+            "{} does not support executing at an explicit address",
+            self.name()
+        ))
+    }
+}
+
+pub(crate) fn decode_hex(s: &str) -> Result<Vec<u8>> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    hex::decode(s).map_err(|e| anyhow::anyhow!("Failed to decode hex: {}", e))
+}
+
+pub(crate) fn make_executor(evm: &str) -> Result<Box<dyn EvmExecutor>> {
+    match evm {
+        "revm" => Ok(Box::new(crate::evms::revm::RevmExecutor::new()?)),
+        "ethrex" => Ok(Box::new(crate::evms::ethrex::EthrexExecutor::new()?)),
+        "guillotine" => Ok(Box::new(crate::evms::guillotine::GuillotineExecutor::new()?)),
+        "wasm" => Ok(Box::new(crate::evms::wasm::WasmExecutor::new()?)),
+        other => Err(anyhow::anyhow!("Unknown EVM implementation: {}", other)),
+    }
+}
+
+/// Decode hex inputs and execute once against `executor`, printing the
+/// result for `Commands::Execute` (this is also what the runner benchmarks
+/// through hyperfine, one process per run). With `trace` set, also dumps an
+/// EIP-3155 per-opcode trace, or, for backends whose `execute_traced` records
+/// no steps, the emitted log list and a per-opcode gas histogram.
+pub fn run_executor(
+    executor: &mut dyn EvmExecutor,
+    bytecode: &str,
+    calldata: &str,
+    gas_limit: u64,
+    mode: ExecutionMode,
+    trace: bool,
+) -> Result<()> {
+    let bytecode = decode_hex(bytecode)?;
+    let calldata = decode_hex(calldata)?;
+
+    if trace {
+        let (result, steps) = executor.execute_traced(bytecode, calldata, gas_limit)?;
+
+        println!("Success: {}", result.success);
+        println!("Gas used: {}", result.gas_used);
+        println!("Output: 0x{}", hex::encode(&result.output));
+
+        if steps.is_empty() {
+            // Backend doesn't implement `execute_traced`: fall back to the
+            // coarser log/gas-histogram dump rather than an empty trace.
+            println!("\nLogs ({}):", result.logs.len());
+            for log in &result.logs {
+                println!(
+                    "  address=0x{} topics={:?} data=0x{}",
+                    hex::encode(log.address),
+                    log.topics.iter().map(hex::encode).collect::<Vec<_>>(),
+                    hex::encode(&log.data),
+                );
+            }
+
+            println!("\nGas histogram ({} opcodes):", result.gas_profile.len());
+            for (opcode, gas) in &result.gas_profile {
+                println!("  0x{:02x}: {} gas", opcode, gas);
+            }
+        } else {
+            println!("\nEIP-3155 trace ({} steps):", steps.len());
+            crate::trace::print_eip3155(&steps, &result.output, result.gas_used, result.success)?;
+        }
+
+        return Ok(());
+    }
+
+    let result = executor.execute_with_mode(bytecode, calldata, gas_limit, mode)?;
+
+    println!("Success: {}", result.success);
+    println!("Gas used: {}", result.gas_used);
+    println!("Output: 0x{}", hex::encode(&result.output));
+
+    Ok(())
+}
+
+/// Build the named backend and run `run_executor` against it.
+pub fn execute_bytecode(evm: &str, bytecode: &str, calldata: &str, gas_limit: u64, mode: ExecutionMode) -> Result<()> {
+    run_executor(make_executor(evm)?.as_mut(), bytecode, calldata, gas_limit, mode, false)
+}
+
+/// Build the named backend and run `run_executor` against it with tracing enabled.
+pub fn trace_bytecode(evm: &str, bytecode: &str, calldata: &str, gas_limit: u64, mode: ExecutionMode) -> Result<()> {
+    run_executor(make_executor(evm)?.as_mut(), bytecode, calldata, gas_limit, mode, true)
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use anyhow::{Result, anyhow, Context};
-use crate::evm::{EvmResult, EvmExecutor};
+use crate::evm::{EvmResult, EvmExecutor, Capabilities, BytecodeKind, TraceStep, ExecStatus};
 use ethrex_common::{Address as EthrexAddress, H256, U256};
 use ethrex_common::types::{Transaction, LegacyTransaction, TxKind, Account};
 use ethrex_vm::DynVmDatabase;
@@ -22,6 +22,24 @@ impl EthrexExecutor {
     }
 }
 
+/// levm's `ExecutionResult` doesn't expose a revert-vs-halt distinction
+/// directly, so this falls back to the heuristic every EVM implementation
+/// agrees on in practice: a `REVERT` always carries its revert reason (even
+/// if empty) as output, while the other halt conditions (out-of-gas,
+/// invalid opcode, stack over/underflow, ...) never produce output. A halt
+/// that happens to leave return-data behind from an earlier nested call
+/// would be misclassified by this, so treat `status` here as best-effort
+/// rather than verified.
+fn classify_status(success: bool, output: &[u8]) -> ExecStatus {
+    if success {
+        ExecStatus::Success
+    } else if !output.is_empty() {
+        ExecStatus::Revert
+    } else {
+        ExecStatus::Halt { reason: "unknown (not surfaced by levm's ExecutionResult)".to_string() }
+    }
+}
+
 impl EvmExecutor for EthrexExecutor {
     fn execute(
         &mut self,
@@ -107,12 +125,135 @@ impl EvmExecutor for EthrexExecutor {
         Ok(EvmResult {
             success: result.is_success(),
             gas_used: result.gas_used,
+            // levm's `ExecutionResult` doesn't surface a refund total on
+            // this path, so it's best-effort until that's exposed.
+            refunded: 0,
+            status: classify_status(result.is_success(), &result.output),
             output: result.output,
             logs: Vec::new(),
+            gas_profile: Vec::new(),
         })
     }
-    
+
     fn name(&self) -> &str {
         "ethrex"
     }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            bytecode_kind: BytecodeKind::Evm,
+            supports_state: true,
+            supports_logs: false,
+            supports_tracing: true,
+            has_jit: false,
+        }
+    }
+
+    /// Swaps in an enabled `LevmCallTracer` (the `execute` path above always
+    /// passes `::disabled()`) and translates its recorded steps into the
+    /// EIP-3155 `TraceStep` shape so ethrex traces are line-diffable against
+    /// revm's.
+    fn execute_traced(
+        &mut self,
+        bytecode: Vec<u8>,
+        calldata: Vec<u8>,
+        gas_limit: u64,
+    ) -> Result<(EvmResult, Vec<TraceStep>)> {
+        use ethrex_vm::levm::{
+            vm::{VM, VMType},
+            Environment,
+            EVMConfig,
+            tracing::LevmCallTracer,
+            db::gen_db::GeneralizedDatabase,
+        };
+
+        let mut initial_state = BTreeMap::new();
+
+        initial_state.insert(
+            self.caller_address,
+            Account {
+                balance: U256::from(1_000_000_000_000_000_000u128),
+                nonce: 0,
+                code: vec![],
+                storage: Default::default(),
+            },
+        );
+
+        initial_state.insert(
+            self.contract_address,
+            Account {
+                balance: U256::ZERO,
+                nonce: 1,
+                code: bytecode.clone(),
+                storage: Default::default(),
+            },
+        );
+
+        let in_memory_db = Store::new("", ethrex_storage::EngineType::InMemory)
+            .map_err(|e| anyhow!("Failed to create in-memory store: {:?}", e))?;
+        let store: DynVmDatabase = Box::new(StoreVmDatabase::new(in_memory_db, H256::zero()));
+        let mut db = GeneralizedDatabase::new_with_account_state(Arc::new(store), initial_state);
+
+        let env = Environment {
+            origin: self.caller_address,
+            gas_limit,
+            gas_price: U256::from(1_000_000_000u128),
+            block_gas_limit: u64::MAX,
+            config: EVMConfig::default(),
+            coinbase: EthrexAddress::from([0x77; 20]),
+            ..Default::default()
+        };
+
+        let tx = Transaction::LegacyTransaction(LegacyTransaction {
+            nonce: 0,
+            gas_price: 1_000_000_000,
+            gas: gas_limit,
+            to: TxKind::Call(self.contract_address),
+            value: U256::ZERO,
+            data: calldata.into(),
+            v: 27,
+            r: U256::ZERO,
+            s: U256::ZERO,
+        });
+
+        let tracer = LevmCallTracer::enabled();
+
+        let mut vm = VM::new(
+            env,
+            &mut db,
+            &tx,
+            tracer.clone(),
+            VMType::L1,
+        ).map_err(|e| anyhow!("Failed to create VM: {:?}", e))?;
+
+        let result = vm.execute()
+            .map_err(|e| anyhow!("VM execution failed: {:?}", e))?;
+
+        let steps = tracer
+            .steps()
+            .iter()
+            .map(|step| TraceStep {
+                pc: step.pc as u64,
+                op: step.opcode,
+                gas: step.gas,
+                gas_cost: step.gas_cost,
+                depth: step.depth as u64,
+                stack: step.stack.clone(),
+                mem_size: None,
+            })
+            .collect();
+
+        Ok((
+            EvmResult {
+                success: result.is_success(),
+                gas_used: result.gas_used,
+                refunded: 0,
+                status: classify_status(result.is_success(), &result.output),
+                output: result.output,
+                logs: Vec::new(),
+                gas_profile: Vec::new(),
+            },
+            steps,
+        ))
+    }
 }
\ No newline at end of file
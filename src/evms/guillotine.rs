@@ -1,6 +1,6 @@
 use anyhow::{Result, anyhow};
 use guillotine_ffi::{Evm, Address, U256};
-use crate::evm::{EvmResult, EvmExecutor};
+use crate::evm::{EvmResult, EvmExecutor, Capabilities, BytecodeKind, Log, ExecStatus, AccessListEntry};
 
 pub struct GuillotineExecutor {
     evm: Evm,
@@ -8,6 +8,23 @@ pub struct GuillotineExecutor {
     caller_address: Address,
 }
 
+/// The FFI boundary doesn't expose a revert-vs-halt distinction directly,
+/// so this falls back to the same heuristic used for `levm`: a revert
+/// always carries its revert reason (even if empty) as output, while the
+/// other halt conditions (out of gas, invalid opcode, ...) never produce
+/// output. A halt that happens to leave return-data behind from an
+/// earlier nested call would be misclassified by this, so treat `status`
+/// here as best-effort rather than verified.
+fn classify_status(success: bool, output: &[u8]) -> ExecStatus {
+    if success {
+        ExecStatus::Success
+    } else if !output.is_empty() {
+        ExecStatus::Revert
+    } else {
+        ExecStatus::Halt { reason: "unknown (not surfaced by guillotine FFI)".to_string() }
+    }
+}
+
 impl GuillotineExecutor {
     pub fn new() -> Result<Self> {
         let evm = Evm::new()
@@ -46,15 +63,165 @@ impl EvmExecutor for GuillotineExecutor {
             .execute()
             .map_err(|e| anyhow!("Failed to execute transaction: {}", e))?;
         
+        let logs = result.logs()
+            .iter()
+            .map(|log| Log {
+                address: log.address,
+                topics: log.topics.clone(),
+                data: log.data.clone(),
+            })
+            .collect();
+
+        let output = result.output().to_vec();
+        let status = classify_status(result.is_success(), &output);
+
         Ok(EvmResult {
             success: result.is_success(),
             gas_used: result.gas_used,
-            output: result.output().to_vec(),
-            logs: Vec::new(),
+            refunded: 0,
+            output,
+            logs,
+            gas_profile: result.gas_profile().to_vec(),
+            status,
         })
     }
-    
+
     fn name(&self) -> &str {
         "guillotine"
     }
-}
\ No newline at end of file
+
+    fn set_balance(&mut self, address: [u8; 20], balance: [u8; 32]) -> Result<()> {
+        self.evm
+            .set_balance(Address::from(address), U256::from_be_bytes(balance))
+            .map_err(|e| anyhow!("Failed to set balance: {}", e))
+    }
+
+    fn set_code(&mut self, address: [u8; 20], code: &[u8]) -> Result<()> {
+        self.evm
+            .set_code(Address::from(address), code)
+            .map_err(|e| anyhow!("Failed to set code: {}", e))
+    }
+
+    fn set_storage(&mut self, address: [u8; 20], key: [u8; 32], value: [u8; 32]) -> Result<()> {
+        self.evm
+            .set_storage(Address::from(address), key, value)
+            .map_err(|e| anyhow!("Failed to set storage: {}", e))
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            bytecode_kind: BytecodeKind::Evm,
+            supports_state: true,
+            supports_logs: true,
+            // The FFI boundary has no per-step callback, so `execute_traced`
+            // falls back to the trait default (no steps) rather than
+            // fabricating a trace.
+            supports_tracing: false,
+            // `execute_with_mode` has no real JIT/AOT path to select
+            // (`Evm::transact` always runs the same way regardless of
+            // `ExecutionMode`), so this is left to the trait default, which
+            // reports `Jit` as unsupported instead of silently timing the
+            // interpreter and labeling it "jit".
+            has_jit: false,
+        }
+    }
+
+    /// Runs against an already-seeded `to` instead of overwriting it with
+    /// `bytecode` at the fixed `self.contract_address` the way `execute`
+    /// does, so a caller that seeded code/storage directly at `to` (e.g.
+    /// `state_tests::run_state_test`, from a fixture's real `pre` addresses)
+    /// gets real `SLOAD`s instead of empty storage.
+    fn execute_at(
+        &mut self,
+        to: [u8; 20],
+        calldata: Vec<u8>,
+        gas_limit: u64,
+    ) -> Result<EvmResult> {
+        self.evm.set_balance(self.caller_address, U256::from(1_000_000_000_000_000_000u128))
+            .map_err(|e| anyhow!("Failed to set caller balance: {}", e))?;
+
+        let result = self.evm.transact()
+            .from(self.caller_address)
+            .to(Address::from(to))
+            .input(calldata)
+            .gas_limit(gas_limit)
+            .execute()
+            .map_err(|e| anyhow!("Failed to execute transaction: {}", e))?;
+
+        let logs = result.logs()
+            .iter()
+            .map(|log| Log {
+                address: log.address,
+                topics: log.topics.clone(),
+                data: log.data.clone(),
+            })
+            .collect();
+
+        let output = result.output().to_vec();
+        let status = classify_status(result.is_success(), &output);
+
+        Ok(EvmResult {
+            success: result.is_success(),
+            gas_used: result.gas_used,
+            refunded: 0,
+            output,
+            logs,
+            gas_profile: result.gas_profile().to_vec(),
+            status,
+        })
+    }
+
+    /// Marks every (address, key) pair in `access_list` warm before the call
+    /// via the transaction builder, mirroring how `revm`'s `TxEnv` takes an
+    /// access list, so EIP-2929 warm/cold gas accounting is reflected rather
+    /// than every slot starting cold.
+    fn execute_with_access_list(
+        &mut self,
+        bytecode: Vec<u8>,
+        calldata: Vec<u8>,
+        gas_limit: u64,
+        access_list: &[AccessListEntry],
+    ) -> Result<EvmResult> {
+        self.evm.set_balance(self.caller_address, U256::from(1_000_000_000_000_000_000u128))
+            .map_err(|e| anyhow!("Failed to set caller balance: {}", e))?;
+
+        self.evm.set_code(self.contract_address, &bytecode)
+            .map_err(|e| anyhow!("Failed to set contract code: {}", e))?;
+
+        let mut builder = self.evm.transact()
+            .from(self.caller_address)
+            .to(self.contract_address)
+            .input(calldata)
+            .gas_limit(gas_limit);
+
+        for (address, keys) in access_list {
+            builder = builder.access_list_entry(Address::from(*address), keys.clone());
+        }
+
+        let result = builder
+            .execute()
+            .map_err(|e| anyhow!("Failed to execute transaction: {}", e))?;
+
+        let logs = result.logs()
+            .iter()
+            .map(|log| Log {
+                address: log.address,
+                topics: log.topics.clone(),
+                data: log.data.clone(),
+            })
+            .collect();
+
+        let output = result.output().to_vec();
+        let status = classify_status(result.is_success(), &output);
+
+        Ok(EvmResult {
+            success: result.is_success(),
+            gas_used: result.gas_used,
+            refunded: 0,
+            output,
+            logs,
+            gas_profile: result.gas_profile().to_vec(),
+            status,
+        })
+    }
+}
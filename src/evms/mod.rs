@@ -0,0 +1,4 @@
+pub mod guillotine;
+pub mod revm;
+pub mod ethrex;
+pub mod wasm;
@@ -1,32 +1,138 @@
 use anyhow::Result;
 use revm::{
     context::{Context, TxEnv},
+    context::transaction::{AccessList, AccessListItem},
     context_interface::result::{ExecutionResult, Output},
     database::CacheDB,
-    database_interface::EmptyDB,
-    primitives::{Address, U256, Bytes, TxKind, keccak256, KECCAK_EMPTY},
+    database_interface::{EmptyDB, Database},
+    primitives::{Address, U256, B256, Bytes, TxKind, keccak256, KECCAK_EMPTY},
     bytecode::Bytecode,
     state::AccountInfo,
-    ExecuteCommitEvm, MainBuilder, MainContext,
+    interpreter::{Interpreter, Inspector, CallInputs, CallOutcome, CreateInputs, CreateOutcome},
+    ExecuteCommitEvm, InspectCommitEvm, MainBuilder, MainContext,
 };
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::str::FromStr;
-use crate::evm::{EvmResult, EvmExecutor};
+use crate::evm::{EvmResult, EvmExecutor, Capabilities, BytecodeKind, ExecutionContext, TraceStep, ExecStatus};
+use crate::fork_db::ProofDb;
+
+/// Convert revm's native `Log`s (from `ExecutionResult::Success`) into the
+/// crate's backend-agnostic `Log`, the same shape `guillotine.rs` already
+/// populates, so benchmarks like `erc20_transfer_bench` can assert a
+/// `Transfer` event was actually emitted instead of always seeing an empty
+/// log list.
+fn convert_logs(logs: Vec<revm::primitives::Log>) -> Vec<crate::evm::Log> {
+    logs.into_iter()
+        .map(|log| crate::evm::Log {
+            address: log.address.into_array(),
+            topics: log.data.topics().iter().map(|t| t.0).collect(),
+            data: log.data.data.to_vec(),
+        })
+        .collect()
+}
+
+/// A `revm::Inspector` that records one `TraceStep` per executed opcode, so
+/// `--trace` can emit an EIP-3155 trace instead of only a gas histogram.
+#[derive(Clone, Default)]
+struct StepRecorder {
+    steps: Rc<RefCell<Vec<TraceStep>>>,
+    /// Remaining gas as of the previous step, so each step's `gas_cost` can
+    /// be derived as the delta from it (EIP-3155 requires `gasCost`, and
+    /// revm's `Interpreter` only exposes a running remaining-gas counter,
+    /// not a per-opcode cost).
+    prev_gas: Rc<RefCell<Option<u64>>>,
+    /// Current call depth, incremented/decremented via the `call`/`create`
+    /// hooks below so steps inside a nested call are tagged with their real
+    /// depth instead of always 0.
+    depth: Rc<RefCell<u64>>,
+}
+
+impl<CTX> Inspector<CTX> for StepRecorder {
+    fn step(&mut self, interp: &mut Interpreter, _context: &mut CTX) {
+        let stack = interp
+            .stack
+            .data()
+            .iter()
+            .map(|word| word.to_be_bytes())
+            .collect();
+
+        let gas = interp.gas.remaining();
+        let gas_cost = self.prev_gas.borrow_mut().replace(gas)
+            .map(|prev| prev.saturating_sub(gas))
+            .unwrap_or(0);
+
+        self.steps.borrow_mut().push(TraceStep {
+            pc: interp.bytecode.pc() as u64,
+            op: interp.bytecode.opcode(),
+            gas,
+            gas_cost,
+            depth: *self.depth.borrow(),
+            stack,
+            mem_size: Some(interp.memory.size() as u64),
+        });
+    }
+
+    fn call(&mut self, _context: &mut CTX, _inputs: &mut CallInputs) -> Option<CallOutcome> {
+        *self.depth.borrow_mut() += 1;
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, _outcome: &mut CallOutcome) {
+        *self.depth.borrow_mut() -= 1;
+    }
+
+    fn create(&mut self, _context: &mut CTX, _inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        *self.depth.borrow_mut() += 1;
+        None
+    }
+
+    fn create_end(&mut self, _context: &mut CTX, _inputs: &CreateInputs, _outcome: &mut CreateOutcome) {
+        *self.depth.borrow_mut() -= 1;
+    }
+}
 
 pub struct RevmExecutor {
     contract_address: Address,
     caller_address: Address,
+    /// When set, missing accounts/storage are resolved from this live node
+    /// pinned at a fixed block instead of starting from empty state.
+    fork: Option<(String, u64)>,
+    /// Path to a `fork_db::prefetch_accounts` cache file to seed each fresh
+    /// `ProofDb` from, so a matrix of hyperfine-spawned processes sharing the
+    /// same fork doesn't each re-issue the same RPC calls serially.
+    fork_cache: Option<std::path::PathBuf>,
 }
 
 impl RevmExecutor {
     pub fn new() -> Result<Self> {
         let contract_address = Address::from_str("0x1000000000000000000000000000000000000000")?;
         let caller_address = Address::from_str("0x0000000000000000000000000000000000000001")?;
-        
+
         Ok(Self {
             contract_address,
             caller_address,
+            fork: None,
+            fork_cache: None,
         })
     }
+
+    /// Build a `RevmExecutor` that resolves missing state from `rpc_url` at
+    /// `block`, so real deployed contracts can be benchmarked against pinned
+    /// mainnet state instead of synthetic accounts.
+    pub fn with_fork(rpc_url: String, block: u64) -> Result<Self> {
+        let mut executor = Self::new()?;
+        executor.fork = Some((rpc_url, block));
+        Ok(executor)
+    }
+
+    /// Same as `with_fork`, but also seeds each `ProofDb` from a prefetch
+    /// cache file written by `fork_db::prefetch_accounts`.
+    pub fn with_fork_and_cache(rpc_url: String, block: u64, fork_cache: std::path::PathBuf) -> Result<Self> {
+        let mut executor = Self::with_fork(rpc_url, block)?;
+        executor.fork_cache = Some(fork_cache);
+        Ok(executor)
+    }
 }
 
 impl EvmExecutor for RevmExecutor {
@@ -36,95 +142,299 @@ impl EvmExecutor for RevmExecutor {
         calldata: Vec<u8>,
         gas_limit: u64,
     ) -> Result<EvmResult> {
-        // Create a fresh database for each execution
+        match &self.fork {
+            None => {
+                let mut cache_db = CacheDB::<EmptyDB>::default();
+                seed_accounts(&mut cache_db, self.contract_address, self.caller_address, &bytecode);
+                run_tx(cache_db, self.contract_address, self.caller_address, calldata, gas_limit)
+            }
+            Some((rpc_url, block)) => {
+                let mut cache_db = CacheDB::new(ProofDb::with_cache(rpc_url.clone(), *block, self.fork_cache.as_deref())?);
+                seed_accounts(&mut cache_db, self.contract_address, self.caller_address, &bytecode);
+                run_tx(cache_db, self.contract_address, self.caller_address, calldata, gas_limit)
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "revm"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            bytecode_kind: BytecodeKind::Evm,
+            supports_state: true,
+            supports_logs: true,
+            supports_tracing: true,
+            has_jit: false,
+        }
+    }
+
+    fn execute_traced(
+        &mut self,
+        bytecode: Vec<u8>,
+        calldata: Vec<u8>,
+        gas_limit: u64,
+    ) -> Result<(EvmResult, Vec<TraceStep>)> {
         let mut cache_db = CacheDB::<EmptyDB>::default();
-        
-        // Insert the contract code into the database as deployed code
-        let bytecode_hash = keccak256(&bytecode);
-        cache_db.insert_account_info(
-            self.contract_address,
-            AccountInfo {
-                balance: U256::ZERO,
-                nonce: 1,
-                code_hash: bytecode_hash,
-                code: Some(Bytecode::new_raw(Bytes::from(bytecode))),
-            },
-        );
-        
-        // Also fund the caller account
-        cache_db.insert_account_info(
-            self.caller_address,
-            AccountInfo {
-                balance: U256::from(1_000_000_000_000_000_000u128), // 1 ETH
-                nonce: 0,
-                code_hash: KECCAK_EMPTY,
-                code: None,
-            },
-        );
-        
-        // Build transaction
+        seed_accounts(&mut cache_db, self.contract_address, self.caller_address, &bytecode);
+
         let tx = TxEnv::builder()
             .caller(self.caller_address)
             .kind(TxKind::Call(self.contract_address))
             .data(Bytes::from(calldata))
             .gas_limit(gas_limit)
-            .gas_price(1_000_000_000u128) // 1 gwei
+            .gas_price(1_000_000_000u128)
             .build()
             .unwrap();
-        
-        // Build context and EVM
-        let ctx = Context::mainnet()
-            .with_db(cache_db);
-        
-        let mut evm = ctx.build_mainnet();
-        
-        // Execute the transaction
-        let result = evm.transact_commit(tx);
-        
-        // Check execution result
-        match result {
-            Ok(exec_result) => {
-                match exec_result {
-                    ExecutionResult::Success { 
-                        gas_used, 
-                        output, 
-                        .. 
-                    } => {
-                        let output_bytes = match output {
-                            Output::Call(bytes) => bytes.to_vec(),
-                            Output::Create(bytes, _) => bytes.to_vec(),
-                        };
-                        
-                        Ok(EvmResult {
-                            success: true,
-                            gas_used: gas_used as u64,
-                            output: output_bytes,
-                            logs: Vec::new(),
-                        })
-                    }
-                    ExecutionResult::Revert { gas_used, output } => {
-                        Ok(EvmResult {
-                            success: false,
-                            gas_used: gas_used as u64,
-                            output: output.to_vec(),
-                            logs: Vec::new(),
-                        })
-                    }
-                    ExecutionResult::Halt { reason, gas_used } => {
-                        Ok(EvmResult {
-                            success: false,
-                            gas_used: gas_used as u64,
-                            output: format!("Halted: {:?}", reason).into_bytes(),
-                            logs: Vec::new(),
-                        })
-                    }
-                }
+
+        let recorder = StepRecorder::default();
+        let steps = recorder.steps.clone();
+
+        let ctx = Context::mainnet().with_db(cache_db);
+        let mut evm = ctx.build_mainnet_with_inspector(recorder);
+
+        let result = evm
+            .inspect_tx_commit(tx)
+            .map_err(|e| anyhow::anyhow!("EVM execution error: {:?}", e))?;
+
+        let evm_result = match result {
+            ExecutionResult::Success { gas_used, gas_refunded, output, logs, .. } => EvmResult {
+                success: true,
+                gas_used: gas_used as u64,
+                refunded: gas_refunded as u64,
+                output: match output {
+                    Output::Call(bytes) => bytes.to_vec(),
+                    Output::Create(bytes, _) => bytes.to_vec(),
+                },
+                logs: convert_logs(logs),
+                gas_profile: Vec::new(),
+                status: ExecStatus::Success,
+            },
+            ExecutionResult::Revert { gas_used, output } => EvmResult {
+                success: false,
+                gas_used: gas_used as u64,
+                refunded: 0,
+                output: output.to_vec(),
+                logs: Vec::new(),
+                gas_profile: Vec::new(),
+                status: ExecStatus::Revert,
+            },
+            ExecutionResult::Halt { reason, gas_used } => EvmResult {
+                success: false,
+                gas_used: gas_used as u64,
+                refunded: 0,
+                output: format!("Halted: {:?}", reason).into_bytes(),
+                logs: Vec::new(),
+                gas_profile: Vec::new(),
+                status: ExecStatus::Halt { reason: format!("{:?}", reason) },
+            },
+        };
+
+        Ok((evm_result, steps.borrow().clone()))
+    }
+
+    /// Unlike the default no-frills `execute`, this honors `ctx.prestate`
+    /// (additional pre-funded accounts with code/storage), `ctx.access_list`
+    /// (marked warm via `TxEnv`), and `ctx.caller`/`ctx.value`/`ctx.gas_price`,
+    /// so fixtures can measure warm-vs-cold storage access and calls into
+    /// already-initialized accounts rather than always starting empty.
+    fn execute_with_context(&mut self, ctx: ExecutionContext) -> Result<EvmResult> {
+        let access_list = AccessList(
+            ctx.access_list
+                .iter()
+                .map(|(address, keys)| AccessListItem {
+                    address: Address::from(*address),
+                    storage_keys: keys.iter().map(|k| B256::from(*k)).collect(),
+                })
+                .collect(),
+        );
+
+        let tx = TxEnv::builder()
+            .caller(Address::from(ctx.caller))
+            .kind(TxKind::Call(self.contract_address))
+            .data(Bytes::from(ctx.calldata.clone()))
+            .value(U256::from_be_bytes(ctx.value))
+            .gas_limit(ctx.gas_limit)
+            .gas_price(ctx.gas_price as u128)
+            .access_list(access_list)
+            .build()
+            .unwrap();
+
+        match &self.fork {
+            None => {
+                let mut cache_db = CacheDB::<EmptyDB>::default();
+                seed_accounts(&mut cache_db, self.contract_address, self.caller_address, &ctx.bytecode);
+                seed_prestate(&mut cache_db, &ctx.prestate);
+                commit_tx(cache_db, tx)
+            }
+            Some((rpc_url, block)) => {
+                let mut cache_db = CacheDB::new(ProofDb::with_cache(rpc_url.clone(), *block, self.fork_cache.as_deref())?);
+                seed_accounts(&mut cache_db, self.contract_address, self.caller_address, &ctx.bytecode);
+                seed_prestate(&mut cache_db, &ctx.prestate);
+                commit_tx(cache_db, tx)
             }
-            Err(e) => Err(anyhow::anyhow!("EVM execution error: {:?}", e))
         }
     }
-    
-    fn name(&self) -> &str {
-        "revm"
+
+    /// Delegates to `execute_with_context` so the extra accounts are seeded
+    /// into the same `CacheDB` as the contract/caller in one pass, rather
+    /// than through the default's `set_balance`/`set_code`/`set_storage`
+    /// loop (which `RevmExecutor` doesn't implement, since it always builds
+    /// a fresh `CacheDB` per call instead of mutating persistent state).
+    fn execute_with_prestate(
+        &mut self,
+        bytecode: Vec<u8>,
+        calldata: Vec<u8>,
+        gas_limit: u64,
+        pre_state: &std::collections::HashMap<[u8; 20], crate::evm::PrestateAccount>,
+    ) -> Result<EvmResult> {
+        self.execute_with_context(ExecutionContext {
+            bytecode,
+            calldata,
+            gas_limit,
+            caller: self.caller_address.into_array(),
+            value: [0u8; 32],
+            gas_price: 1_000_000_000,
+            prestate: pre_state.clone(),
+            access_list: Vec::new(),
+        })
     }
-}
\ No newline at end of file
+}
+
+/// Overlay `prestate`'s accounts and storage slots on top of whatever
+/// `cache_db` already holds, so a fixture can fund/initialize additional
+/// accounts beyond the benchmark's own contract and caller.
+fn seed_prestate<ExtDB: Database>(
+    cache_db: &mut CacheDB<ExtDB>,
+    prestate: &std::collections::HashMap<[u8; 20], crate::evm::PrestateAccount>,
+) {
+    for (address, account) in prestate {
+        let address = Address::from(*address);
+        let code = if account.code.is_empty() {
+            None
+        } else {
+            Some(Bytecode::new_raw(Bytes::from(account.code.clone())))
+        };
+        cache_db.insert_account_info(
+            address,
+            AccountInfo {
+                balance: U256::from_be_bytes(account.balance),
+                nonce: account.nonce,
+                code_hash: code.as_ref().map(|c| c.hash_slow()).unwrap_or(KECCAK_EMPTY),
+                code,
+            },
+        );
+        for (key, value) in &account.storage {
+            cache_db
+                .insert_account_storage(address, U256::from_be_bytes(*key), U256::from_be_bytes(*value))
+                .unwrap();
+        }
+    }
+}
+
+/// Overwrite the contract/caller accounts with the benchmark's bytecode and
+/// a funded balance, on top of whatever the underlying database (empty or
+/// fork-backed) already holds for them.
+fn seed_accounts<ExtDB: Database>(
+    cache_db: &mut CacheDB<ExtDB>,
+    contract_address: Address,
+    caller_address: Address,
+    bytecode: &[u8],
+) {
+    let bytecode_hash = keccak256(bytecode);
+    cache_db.insert_account_info(
+        contract_address,
+        AccountInfo {
+            balance: U256::ZERO,
+            nonce: 1,
+            code_hash: bytecode_hash,
+            code: Some(Bytecode::new_raw(Bytes::from(bytecode.to_vec()))),
+        },
+    );
+
+    cache_db.insert_account_info(
+        caller_address,
+        AccountInfo {
+            balance: U256::from(1_000_000_000_000_000_000u128), // 1 ETH
+            nonce: 0,
+            code_hash: KECCAK_EMPTY,
+            code: None,
+        },
+    );
+}
+
+fn run_tx<ExtDB: Database>(
+    cache_db: CacheDB<ExtDB>,
+    contract_address: Address,
+    caller_address: Address,
+    calldata: Vec<u8>,
+    gas_limit: u64,
+) -> Result<EvmResult> {
+    let tx = TxEnv::builder()
+        .caller(caller_address)
+        .kind(TxKind::Call(contract_address))
+        .data(Bytes::from(calldata))
+        .gas_limit(gas_limit)
+        .gas_price(1_000_000_000u128) // 1 gwei
+        .build()
+        .unwrap();
+
+    commit_tx(cache_db, tx)
+}
+
+/// Run a pre-built `TxEnv` against `cache_db` and translate the resulting
+/// `ExecutionResult` into an `EvmResult`, shared by both the bare
+/// `(bytecode, calldata, gas_limit)` path and `execute_with_context`.
+fn commit_tx<ExtDB: Database>(cache_db: CacheDB<ExtDB>, tx: TxEnv) -> Result<EvmResult> {
+    let ctx = Context::mainnet().with_db(cache_db);
+    let mut evm = ctx.build_mainnet();
+
+    let result = evm.transact_commit(tx);
+
+    match result {
+        Ok(exec_result) => {
+            match exec_result {
+                ExecutionResult::Success { gas_used, gas_refunded, output, logs, .. } => {
+                    let output_bytes = match output {
+                        Output::Call(bytes) => bytes.to_vec(),
+                        Output::Create(bytes, _) => bytes.to_vec(),
+                    };
+
+                    Ok(EvmResult {
+                        success: true,
+                        gas_used: gas_used as u64,
+                        refunded: gas_refunded as u64,
+                        output: output_bytes,
+                        logs: convert_logs(logs),
+                        gas_profile: Vec::new(),
+                        status: ExecStatus::Success,
+                    })
+                }
+                ExecutionResult::Revert { gas_used, output } => {
+                    Ok(EvmResult {
+                        success: false,
+                        gas_used: gas_used as u64,
+                        refunded: 0,
+                        output: output.to_vec(),
+                        logs: Vec::new(),
+                        gas_profile: Vec::new(),
+                        status: ExecStatus::Revert,
+                    })
+                }
+                ExecutionResult::Halt { reason, gas_used } => {
+                    Ok(EvmResult {
+                        success: false,
+                        gas_used: gas_used as u64,
+                        refunded: 0,
+                        output: format!("Halted: {:?}", reason).into_bytes(),
+                        logs: Vec::new(),
+                        gas_profile: Vec::new(),
+                        status: ExecStatus::Halt { reason: format!("{:?}", reason) },
+                    })
+                }
+            }
+        }
+        Err(e) => Err(anyhow::anyhow!("EVM execution error: {:?}", e))
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::{Result, anyhow};
+use wasmtime::{Config, Engine, Instance, Linker, Module, Store};
+use crate::evm::{EvmResult, EvmExecutor, Capabilities, BytecodeKind, ExecStatus};
+
+/// Executes WASM modules (the Stylus model: Rust/C compiled to
+/// `wasm32-unknown-unknown` and run under a metered VM) through the same
+/// `EvmExecutor` shape the EVM backends use, so `mean`/`gas_used` are
+/// directly comparable across backends.
+pub struct WasmExecutor {
+    engine: Engine,
+}
+
+impl WasmExecutor {
+    pub fn new() -> Result<Self> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+
+        let engine = Engine::new(&config)
+            .map_err(|e| anyhow!("Failed to create wasmtime engine: {}", e))?;
+
+        Ok(Self { engine })
+    }
+}
+
+impl EvmExecutor for WasmExecutor {
+    fn execute(
+        &mut self,
+        bytecode: Vec<u8>,
+        calldata: Vec<u8>,
+        gas_limit: u64,
+    ) -> Result<EvmResult> {
+        // `bytecode` holds the WASM module bytes here rather than EVM opcodes,
+        // so the two backends can be benchmarked through one interface.
+        let module = Module::new(&self.engine, &bytecode)
+            .map_err(|e| anyhow!("Failed to load WASM module: {}", e))?;
+
+        let mut store = Store::new(&self.engine, calldata);
+        store
+            .set_fuel(gas_limit)
+            .map_err(|e| anyhow!("Failed to set fuel: {}", e))?;
+
+        let linker = Linker::new(&self.engine);
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .map_err(|e| anyhow!("Failed to instantiate WASM module: {}", e))?;
+
+        let (success, output) = run_entrypoint(&mut store, &instance)?;
+
+        let gas_used = gas_limit.saturating_sub(store.get_fuel().unwrap_or(0));
+
+        Ok(EvmResult {
+            success,
+            gas_used,
+            refunded: 0,
+            output,
+            logs: Vec::new(),
+            gas_profile: Vec::new(),
+            status: if success {
+                ExecStatus::Success
+            } else {
+                ExecStatus::Halt { reason: "trap (not further classified by the fuel-metered host)".to_string() }
+            },
+        })
+    }
+
+    fn name(&self) -> &str {
+        "wasm"
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            bytecode_kind: BytecodeKind::Wasm,
+            supports_state: false,
+            supports_logs: false,
+            supports_tracing: false,
+            has_jit: false,
+        }
+    }
+}
+
+/// Calls the module's exported `benchmark` entrypoint, reading calldata from
+/// the store and writing output back through a returned pointer/length pair.
+fn run_entrypoint(
+    store: &mut Store<Vec<u8>>,
+    instance: &Instance,
+) -> Result<(bool, Vec<u8>)> {
+    let memory = instance
+        .get_memory(&mut *store, "memory")
+        .ok_or_else(|| anyhow!("WASM module has no exported memory"))?;
+
+    let calldata = store.data().clone();
+    let calldata_ptr: u32 = 0;
+    memory
+        .write(&mut *store, calldata_ptr as usize, &calldata)
+        .map_err(|e| anyhow!("Failed to write calldata into WASM memory: {}", e))?;
+
+    let benchmark = instance
+        .get_typed_func::<(u32, u32), u64>(&mut *store, "benchmark")
+        .map_err(|e| anyhow!("WASM module has no `benchmark(ptr, len) -> packed_result` export: {}", e))?;
+
+    // A trap (including running out of fuel) is a failed execution, reported
+    // like an EVM halt, not a harness error.
+    let packed_result = match benchmark.call(&mut *store, (calldata_ptr, calldata.len() as u32)) {
+        Ok(packed_result) => packed_result,
+        Err(_) => return Ok((false, Vec::new())),
+    };
+
+    // Output pointer/length are packed into the high/low 32 bits of the result.
+    let output_ptr = (packed_result >> 32) as u32 as usize;
+    let output_len = (packed_result & 0xFFFF_FFFF) as u32 as usize;
+
+    let mut output = vec![0u8; output_len];
+    memory
+        .read(&*store, output_ptr, &mut output)
+        .map_err(|e| anyhow!("Failed to read output from WASM memory: {}", e))?;
+
+    Ok((true, output))
+}
@@ -0,0 +1,269 @@
+use anyhow::{Result, Context, anyhow};
+use revm::database_interface::{Database, DBErrorMarker};
+use revm::primitives::{Address, U256, B256, Bytes};
+use revm::bytecode::Bytecode;
+use revm::state::AccountInfo;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// On-disk shape of a prefetched `ProofDb` cache: since each benchmark still
+/// runs in its own hyperfine-spawned subprocess (a fresh `ProofDb` per
+/// process), the only way to avoid re-issuing the same RPC calls serially
+/// across that whole matrix is to persist the prefetch to a file that every
+/// subprocess loads from before falling back to the network.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ForkCache {
+    /// Address (hex, no 0x) -> (balance hex, nonce, code hex).
+    accounts: HashMap<String, (String, u64, String)>,
+    /// "{address hex}:{key hex}" -> value hex.
+    storage: HashMap<String, String>,
+}
+
+/// A `revm::Database` that resolves missing accounts/storage on demand from
+/// a live JSON-RPC node pinned at a fixed block, so benchmarks can run
+/// against real deployed contracts instead of only synthetic state.
+pub struct ProofDb {
+    rpc_url: String,
+    block: u64,
+    accounts: RefCell<HashMap<Address, AccountInfo>>,
+    storage: RefCell<HashMap<(Address, U256), U256>>,
+}
+
+#[derive(Debug)]
+pub struct ProofDbError(pub String);
+
+impl fmt::Display for ProofDbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+impl std::error::Error for ProofDbError {}
+impl DBErrorMarker for ProofDbError {}
+
+impl ProofDb {
+    pub fn new(rpc_url: impl Into<String>, block: u64) -> Self {
+        Self {
+            rpc_url: rpc_url.into(),
+            block,
+            accounts: RefCell::new(HashMap::new()),
+            storage: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Build a `ProofDb` that's pre-seeded from a `prefetch_accounts` cache
+    /// file at `cache_path`, if one exists, so this process's first lookups
+    /// for a prefetched address/slot hit the cache instead of the network.
+    /// A missing or unreadable cache file is not an error: it just means
+    /// every lookup falls back to RPC, same as `new`.
+    pub fn with_cache(rpc_url: impl Into<String>, block: u64, cache_path: Option<&Path>) -> Result<Self> {
+        let db = Self::new(rpc_url, block);
+        let Some(path) = cache_path else { return Ok(db) };
+        let Ok(contents) = std::fs::read_to_string(path) else { return Ok(db) };
+        let cache: ForkCache = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse fork cache: {}", path.display()))?;
+
+        let mut accounts = db.accounts.borrow_mut();
+        for (addr_hex, (balance_hex, nonce, code_hex)) in &cache.accounts {
+            let address = Address::from_str(addr_hex)?;
+            let balance = U256::from_str_radix(balance_hex.trim_start_matches("0x"), 16).unwrap_or_default();
+            let code_bytes = hex::decode(code_hex.trim_start_matches("0x")).unwrap_or_default();
+            let code = if code_bytes.is_empty() { None } else { Some(Bytecode::new_raw(Bytes::from(code_bytes))) };
+            accounts.insert(address, AccountInfo {
+                balance,
+                nonce: *nonce,
+                code_hash: code.as_ref().map(|c| c.hash_slow()).unwrap_or_default(),
+                code,
+            });
+        }
+        drop(accounts);
+
+        let mut storage = db.storage.borrow_mut();
+        for (key, value_hex) in &cache.storage {
+            let Some((addr_hex, slot_hex)) = key.split_once(':') else { continue };
+            let address = Address::from_str(addr_hex)?;
+            let slot = U256::from_str_radix(slot_hex.trim_start_matches("0x"), 16).unwrap_or_default();
+            let value = U256::from_str_radix(value_hex.trim_start_matches("0x"), 16).unwrap_or_default();
+            storage.insert((address, slot), value);
+        }
+        drop(storage);
+
+        Ok(db)
+    }
+
+    fn rpc_call(&self, method: &str, params: Value) -> Result<Value> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        // `params` is expected to already end with the block tag where
+        // applicable (eth_getProof/eth_getCode/eth_getStorageAt all take it
+        // as their final positional argument), so no block tag is built here.
+
+        let response: Value = ureq::post(&self.rpc_url)
+            .send_json(body)
+            .with_context(|| format!("RPC call {} failed", method))?
+            .into_json()
+            .context("Failed to parse RPC response as JSON")?;
+
+        if let Some(error) = response.get("error") {
+            return Err(anyhow!("RPC error calling {}: {}", method, error));
+        }
+
+        response.get("result").cloned().ok_or_else(|| anyhow!("RPC response for {} had no result", method))
+    }
+
+    pub(crate) fn fetch_account(&self, address: Address) -> Result<AccountInfo> {
+        let block_hex = format!("0x{:x}", self.block);
+        let addr_hex = format!("0x{:x}", address);
+
+        let proof = self.rpc_call("eth_getProof", json!([addr_hex, [] as [String; 0], block_hex]))?;
+        let balance = U256::from_str_radix(
+            proof["balance"].as_str().unwrap_or("0x0").trim_start_matches("0x"),
+            16,
+        ).unwrap_or_default();
+        let nonce = u64::from_str_radix(
+            proof["nonce"].as_str().unwrap_or("0x0").trim_start_matches("0x"),
+            16,
+        ).unwrap_or_default();
+
+        let code_hex = self.rpc_call("eth_getCode", json!([addr_hex, format!("0x{:x}", self.block)]))?;
+        let code_bytes = hex::decode(code_hex.as_str().unwrap_or("0x").trim_start_matches("0x"))
+            .unwrap_or_default();
+
+        let code = if code_bytes.is_empty() {
+            None
+        } else {
+            Some(Bytecode::new_raw(Bytes::from(code_bytes)))
+        };
+
+        Ok(AccountInfo {
+            balance,
+            nonce,
+            code_hash: code.as_ref().map(|c| c.hash_slow()).unwrap_or_default(),
+            code,
+        })
+    }
+
+    fn fetch_code_hex(&self, address: Address) -> Result<String> {
+        let addr_hex = format!("0x{:x}", address);
+        let code_hex = self.rpc_call("eth_getCode", json!([addr_hex, format!("0x{:x}", self.block)]))?;
+        Ok(code_hex.as_str().unwrap_or("0x").trim_start_matches("0x").to_string())
+    }
+
+    fn fetch_storage(&self, address: Address, key: U256) -> Result<U256> {
+        let addr_hex = format!("0x{:x}", address);
+        let key_hex = format!("0x{:x}", key);
+        let block_hex = format!("0x{:x}", self.block);
+
+        let value = self.rpc_call("eth_getStorageAt", json!([addr_hex, key_hex, block_hex]))?;
+        Ok(U256::from_str_radix(
+            value.as_str().unwrap_or("0x0").trim_start_matches("0x"),
+            16,
+        ).unwrap_or_default())
+    }
+}
+
+/// Fire `eth_getProof`/`eth_getStorageAt` for every `(address, storage keys)`
+/// pair in `entries` against `rpc_url` at `block`, in batches of `batch_size`
+/// concurrent requests, and write the results to `cache_path` as a
+/// `ForkCache`. Each benchmark still runs in its own hyperfine-spawned
+/// subprocess (required for clean timing isolation), so an in-memory
+/// `ProofDb` can't be shared across them directly; writing the prefetch to a
+/// file that `ProofDb::with_cache` loads on construction is what actually
+/// lets those subprocesses skip the serial per-benchmark RPC round trips.
+pub fn prefetch_accounts(
+    rpc_url: &str,
+    block: u64,
+    entries: &[(Address, Vec<U256>)],
+    batch_size: usize,
+    cache_path: &Path,
+) -> Result<()> {
+    let cache = std::sync::Mutex::new(ForkCache::default());
+
+    for batch in entries.chunks(batch_size.max(1)) {
+        std::thread::scope(|scope| -> Result<()> {
+            let handles: Vec<_> = batch
+                .iter()
+                .map(|(address, keys)| {
+                    let address = *address;
+                    scope.spawn(move || -> Result<()> {
+                        let db = ProofDb::new(rpc_url, block);
+                        let info = db.fetch_account(address)?;
+                        let code_hex = db.fetch_code_hex(address)?;
+                        cache.lock().unwrap().accounts.insert(
+                            format!("{:x}", address),
+                            (format!("0x{:x}", info.balance), info.nonce, code_hex),
+                        );
+
+                        for &key in keys {
+                            let value = db.fetch_storage(address, key)?;
+                            cache.lock().unwrap().storage.insert(
+                                format!("{:x}:{:x}", address, key),
+                                format!("0x{:x}", value),
+                            );
+                        }
+                        Ok(())
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().map_err(|_| anyhow!("prefetch thread panicked"))??;
+            }
+            Ok(())
+        })?;
+    }
+
+    let cache = cache.into_inner().unwrap();
+    let json = serde_json::to_string_pretty(&cache)?;
+    std::fs::write(cache_path, json)
+        .with_context(|| format!("Failed to write fork cache: {}", cache_path.display()))?;
+    Ok(())
+}
+
+impl Database for ProofDb {
+    type Error = ProofDbError;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        if let Some(info) = self.accounts.borrow().get(&address) {
+            return Ok(Some(info.clone()));
+        }
+        let info = self.fetch_account(address).map_err(|e| ProofDbError(e.to_string()))?;
+        self.accounts.borrow_mut().insert(address, info.clone());
+        Ok(Some(info))
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        // `CacheDB` always attaches code directly to the `AccountInfo`
+        // returned by `basic`, and only falls back to `code_by_hash` for a
+        // hash it hasn't already resolved through an account lookup. This
+        // `ProofDb` has no RPC method to resolve code by hash alone (only
+        // `eth_getCode` by address), so rather than silently returning empty
+        // code on that path, fail loudly: a caller hitting this has found a
+        // genuine gap instead of getting a wrong-but-quiet answer.
+        Err(ProofDbError(format!(
+            "ProofDb has no way to resolve code by hash alone (hash {:?}); every account's code must be fetched via `basic`",
+            code_hash
+        )))
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        if let Some(value) = self.storage.borrow().get(&(address, index)) {
+            return Ok(*value);
+        }
+        let value = self.fetch_storage(address, index).map_err(|e| ProofDbError(e.to_string()))?;
+        self.storage.borrow_mut().insert((address, index), value);
+        Ok(value)
+    }
+
+    fn block_hash(&mut self, _number: u64) -> Result<B256, Self::Error> {
+        Ok(B256::ZERO)
+    }
+}
@@ -0,0 +1,81 @@
+//! Hex/address/u256 parsing shared by the fixture loaders (`context.rs`'s
+//! `ExecutionContext` fixtures and `state_tests.rs`'s `GeneralStateTests`
+//! fixtures), which otherwise each parse the same `0x`-prefixed hex formats
+//! from JSON.
+
+use anyhow::{bail, Context, Result};
+
+/// Decode a `0x`-prefixed (or bare) hex string, left-padding with a `0`
+/// nibble if it has an odd number of hex digits (as `GeneralStateTests` and
+/// execution-context fixtures sometimes emit, e.g. `"0x0"`).
+pub fn decode_hex(s: &str) -> Result<Vec<u8>> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    let s = if s.len() % 2 == 1 { format!("0{}", s) } else { s.to_string() };
+    Ok(hex::decode(&s)?)
+}
+
+/// Parse a `0x`-prefixed hex integer into a big-endian, left-padded 32-byte word.
+pub fn parse_u256_be(s: &str) -> Result<[u8; 32]> {
+    let bytes = decode_hex(s)?;
+    if bytes.len() > 32 {
+        bail!("Value {} does not fit in 256 bits", s);
+    }
+    let mut out = [0u8; 32];
+    out[32 - bytes.len()..].copy_from_slice(&bytes);
+    Ok(out)
+}
+
+/// Parse a `0x`-prefixed 20-byte address.
+pub fn parse_address(s: &str) -> Result<[u8; 20]> {
+    let bytes = decode_hex(s)?;
+    if bytes.len() != 20 {
+        bail!("Address {} is not 20 bytes", s);
+    }
+    let mut out = [0u8; 20];
+    out.copy_from_slice(&bytes);
+    Ok(out)
+}
+
+/// Parse a `0x`-prefixed hex `u64` (e.g. a gas limit or block number).
+pub fn parse_u64_hex(s: &str) -> Result<u64> {
+    u64::from_str_radix(s.trim_start_matches("0x"), 16)
+        .with_context(|| format!("Invalid integer: {}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_hex_strips_0x_prefix_and_pads_odd_length() {
+        assert_eq!(decode_hex("0x1").unwrap(), vec![0x01]);
+        assert_eq!(decode_hex("abcd").unwrap(), vec![0xab, 0xcd]);
+        assert_eq!(decode_hex("0x").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn parse_u256_be_left_pads_to_32_bytes() {
+        let parsed = parse_u256_be("0x2a").unwrap();
+        assert_eq!(parsed[31], 0x2a);
+        assert!(parsed[..31].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn parse_u256_be_rejects_oversized_input() {
+        let too_big = format!("0x{}", "ff".repeat(33));
+        assert!(parse_u256_be(&too_big).is_err());
+    }
+
+    #[test]
+    fn parse_address_requires_exactly_20_bytes() {
+        // 20 bytes = 40 hex digits.
+        assert!(parse_address("0x00000000000000000000000000000000000000aa").is_ok()); // 20 bytes
+        assert!(parse_address("0x000000000000000000000000000000000000aa").is_err()); // 19 bytes
+        assert!(parse_address("0x00").is_err());
+    }
+
+    #[test]
+    fn parse_u64_hex_parses_0x_prefixed_value() {
+        assert_eq!(parse_u64_hex("0x2a").unwrap(), 42);
+    }
+}
@@ -0,0 +1,309 @@
+//! Calldata generators for the standard Ethereum precompiled contracts, used
+//! to build dedicated precompile microbenchmarks that bypass contract-level
+//! overhead and measure the precompile dispatch path directly.
+
+/// Precompile addresses, as defined by the Ethereum yellow paper / EIPs.
+pub const ECRECOVER: u8 = 0x01;
+pub const SHA256: u8 = 0x02;
+pub const RIPEMD160: u8 = 0x03;
+pub const IDENTITY: u8 = 0x04;
+pub const MODEXP: u8 = 0x05;
+pub const BN128_ADD: u8 = 0x06;
+pub const BN128_MUL: u8 = 0x07;
+pub const BN128_PAIRING: u8 = 0x08;
+pub const BLAKE2F: u8 = 0x09;
+
+/// A single precompile call-data vector at a given input size. `expected_output`,
+/// when set, is a ground-truth oracle value (not just cross-executor
+/// agreement) that the differential harness can assert against directly.
+#[derive(Debug, Clone)]
+pub struct PrecompileVector {
+    pub address: u8,
+    pub label: String,
+    pub calldata: Vec<u8>,
+    pub expected_output: Option<Vec<u8>>,
+}
+
+/// ecrecover(hash, v, r, s) with a syntactically well-formed but not
+/// cryptographically valid signature. Producing and checking a *real*
+/// signature's recovered address would need an actual secp256k1
+/// implementation on this side, which this crate doesn't carry, so this
+/// exercises the dispatch/calldata-layout path without an `expected_output`
+/// oracle; cross-executor agreement (via `DifferentialRunner`) is still
+/// meaningful since every implementation should at least agree on rejecting it.
+fn ecrecover_vector() -> PrecompileVector {
+    let mut calldata = Vec::with_capacity(128);
+    calldata.extend_from_slice(&[0x45; 32]); // hash
+    calldata.extend_from_slice(&[0u8; 31]);
+    calldata.push(28); // v
+    calldata.extend_from_slice(&[0x11; 32]); // r
+    calldata.extend_from_slice(&[0x22; 32]); // s
+
+    PrecompileVector {
+        address: ECRECOVER,
+        label: "ecrecover".to_string(),
+        calldata,
+        expected_output: None,
+    }
+}
+
+/// modexp(base, exponent, modulus) with a large exponent, the expensive case.
+/// `base`/`exponent`/`modulus` are each a single repeated byte, so the
+/// expected result (computed out-of-band) can be pinned as a ground-truth
+/// oracle rather than only a cross-executor comparison.
+fn modexp_vector(bits: usize) -> PrecompileVector {
+    let bytes = bits / 8;
+    // MODEXP's ABI wants `base_len`/`exp_len`/`mod_len` as full 32-byte
+    // big-endian words, not the 8-byte `u64` representation, so the length
+    // itself goes in the last 8 bytes of a 32-byte buffer.
+    let mut len_field = [0u8; 32];
+    len_field[24..].copy_from_slice(&(bytes as u64).to_be_bytes());
+    let mut calldata = Vec::new();
+    calldata.extend_from_slice(&len_field); // base_len
+    calldata.extend_from_slice(&len_field); // exp_len
+    calldata.extend_from_slice(&len_field); // mod_len
+    calldata.extend(std::iter::repeat(0xab).take(bytes)); // base
+    calldata.extend(std::iter::repeat(0xff).take(bytes)); // exponent
+    calldata.extend(std::iter::repeat(0x03).take(bytes)); // modulus
+
+    // base^exponent mod modulus for the repeated-byte inputs above: every
+    // byte of `base` is 57 times the matching byte of `modulus` (0xab = 57 *
+    // 0x03), so `base` is a multiple of `modulus` and the result is all-zero
+    // at every size.
+    let expected_output = Some(vec![0u8; bytes]);
+
+    PrecompileVector {
+        address: MODEXP,
+        label: format!("modexp_{}bit", bits),
+        calldata,
+        expected_output,
+    }
+}
+
+/// BN128 add/mul take two G1 points (or one point + scalar); use the
+/// generator point repeated, which is cheap to construct and always valid.
+fn bn128_generator_point() -> [u8; 64] {
+    let mut point = [0u8; 64];
+    point[31] = 1; // x = 1
+    point[63] = 2; // y = 2
+    point
+}
+
+fn bn128_add_vector() -> PrecompileVector {
+    let p = bn128_generator_point();
+    let mut calldata = Vec::with_capacity(128);
+    calldata.extend_from_slice(&p);
+    calldata.extend_from_slice(&p);
+
+    PrecompileVector {
+        address: BN128_ADD,
+        label: "bn128_add".to_string(),
+        calldata,
+        // Checking the doubled point would need alt_bn128 curve arithmetic
+        // this crate doesn't carry; left to cross-executor agreement.
+        expected_output: None,
+    }
+}
+
+fn bn128_mul_vector() -> PrecompileVector {
+    let p = bn128_generator_point();
+    let mut calldata = Vec::with_capacity(96);
+    calldata.extend_from_slice(&p);
+    calldata.extend_from_slice(&[0u8; 31]);
+    calldata.push(42); // scalar
+
+    PrecompileVector {
+        address: BN128_MUL,
+        label: "bn128_mul".to_string(),
+        calldata,
+        expected_output: None,
+    }
+}
+
+/// `pairs` copies of one (G1, G2) pair. G1 is the generator point (1, 2);
+/// the G2 words are (1, 2, 1, 2), which is not a point on the G2 curve, so
+/// implementations reject this input rather than computing a pairing.
+fn bn128_pairing_vector(pairs: usize) -> PrecompileVector {
+    let g1 = bn128_generator_point();
+    let mut g2 = [0u8; 128];
+    g2[31] = 1;
+    g2[63] = 2;
+    g2[95] = 1;
+    g2[127] = 2;
+
+    let mut calldata = Vec::with_capacity(pairs * 192);
+    for _ in 0..pairs {
+        calldata.extend_from_slice(&g1);
+        calldata.extend_from_slice(&g2);
+    }
+
+    PrecompileVector {
+        address: BN128_PAIRING,
+        label: format!("bn128_pairing_{}pairs", pairs),
+        calldata,
+        // Needs real pairing arithmetic to check; left to cross-executor
+        // agreement.
+        expected_output: None,
+    }
+}
+
+/// blake2f(rounds, h, m, t, f) with a configurable round count.
+fn blake2f_vector(rounds: u32) -> PrecompileVector {
+    let mut calldata = Vec::with_capacity(213);
+    calldata.extend_from_slice(&rounds.to_be_bytes());
+    calldata.extend_from_slice(&[0u8; 64]); // h
+    calldata.extend_from_slice(&[0u8; 128]); // m
+    calldata.extend_from_slice(&[0u8; 16]); // t0, t1
+    calldata.push(1); // f (final block)
+
+    PrecompileVector {
+        address: BLAKE2F,
+        label: format!("blake2f_{}rounds", rounds),
+        calldata,
+        // Needs the real F compression function to check; left to
+        // cross-executor agreement.
+        expected_output: None,
+    }
+}
+
+/// sha256 of `len` bytes of 0xab, pinned (computed out-of-band) as the
+/// expected digest since this is a pure function of its input regardless of
+/// which backend runs it.
+fn sha256_vector(len: usize) -> PrecompileVector {
+    let input = vec![0xab; len];
+    let expected_output = match len {
+        32 => hex::decode("9a2db2e23f1504cd056606553ac049c5e718e8f9ce9233876df1a7a1821af885").ok(),
+        1024 => hex::decode("4555555dc68d872c2270ba89ecc5f6f094812f65372b37e50071fe5168031c49").ok(),
+        _ => None,
+    };
+    PrecompileVector {
+        address: SHA256,
+        label: format!("sha256_{}bytes", len),
+        expected_output,
+        calldata: input,
+    }
+}
+
+fn ripemd160_vector(len: usize) -> PrecompileVector {
+    PrecompileVector {
+        address: RIPEMD160,
+        label: format!("ripemd160_{}bytes", len),
+        calldata: vec![0xab; len],
+        // No ripemd160 implementation on this side to check against; left
+        // to cross-executor agreement.
+        expected_output: None,
+    }
+}
+
+/// identity just echoes its input, so the expected output is the input itself.
+fn identity_vector(len: usize) -> PrecompileVector {
+    let input = vec![0xab; len];
+    PrecompileVector {
+        address: IDENTITY,
+        label: format!("identity_{}bytes", len),
+        expected_output: Some(input.clone()),
+        calldata: input,
+    }
+}
+
+/// All precompile vectors, across representative input sizes, used to seed
+/// the precompile benchmark category.
+pub fn all_vectors() -> Vec<PrecompileVector> {
+    vec![
+        ecrecover_vector(),
+        sha256_vector(32),
+        sha256_vector(1024),
+        ripemd160_vector(32),
+        ripemd160_vector(1024),
+        identity_vector(32),
+        identity_vector(1024),
+        modexp_vector(256),
+        modexp_vector(2048),
+        bn128_add_vector(),
+        bn128_mul_vector(),
+        bn128_pairing_vector(1),
+        bn128_pairing_vector(4),
+        blake2f_vector(12),
+        blake2f_vector(1000),
+    ]
+}
+
+/// A minimal dispatcher stub that forwards the benchmark's calldata straight
+/// to the precompile address via `CALL` and returns whatever it wrote to
+/// return-data, so the existing `EvmExecutor::execute` path can exercise a
+/// precompile directly without a real Solidity contract.
+///
+/// Equivalent to:
+/// ```solidity
+/// (bool ok,) = address(addr).call(msg.data);
+/// assembly { returndatacopy(0, 0, returndatasize()) return(0, returndatasize()) }
+/// ```
+pub fn dispatcher_bytecode(address: u8) -> Vec<u8> {
+    let mut code = Vec::new();
+    // Copy calldata into memory[0..calldatasize).
+    code.extend_from_slice(&[0x36]); // CALLDATASIZE
+    code.extend_from_slice(&[0x60, 0x00]); // PUSH1 0   (offset)
+    code.extend_from_slice(&[0x60, 0x00]); // PUSH1 0   (destOffset)
+    code.extend_from_slice(&[0x37]); // CALLDATACOPY
+
+    // CALL(gas, addr, value=0, argsOffset=0, argsSize=calldatasize, retOffset=0, retSize=0)
+    code.extend_from_slice(&[0x60, 0x00]); // PUSH1 0   (retSize)
+    code.extend_from_slice(&[0x60, 0x00]); // PUSH1 0   (retOffset)
+    code.extend_from_slice(&[0x36]); // CALLDATASIZE    (argsSize)
+    code.extend_from_slice(&[0x60, 0x00]); // PUSH1 0   (argsOffset)
+    code.extend_from_slice(&[0x60, 0x00]); // PUSH1 0   (value)
+    code.extend_from_slice(&[0x60, address]); // PUSH1 <precompile address>
+    code.extend_from_slice(&[0x5a]); // GAS
+    code.extend_from_slice(&[0xf1]); // CALL
+    code.extend_from_slice(&[0x50]); // POP (discard success flag)
+
+    // Copy return-data into memory[0..returndatasize) and return it.
+    code.extend_from_slice(&[0x3d]); // RETURNDATASIZE
+    code.extend_from_slice(&[0x60, 0x00]); // PUSH1 0   (offset)
+    code.extend_from_slice(&[0x60, 0x00]); // PUSH1 0   (destOffset)
+    code.extend_from_slice(&[0x3e]); // RETURNDATACOPY
+    code.extend_from_slice(&[0x3d]); // RETURNDATASIZE  (size)
+    code.extend_from_slice(&[0x60, 0x00]); // PUSH1 0   (offset)
+    code.extend_from_slice(&[0xf3]); // RETURN
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Regression test for the out-of-bounds panic where `modexp_vector`
+    /// sliced an 8-byte `u64::to_be_bytes()` at `[24..]`: every vector must
+    /// build without panicking (the 32-byte length fields themselves are
+    /// checked by `modexp_vector_uses_32_byte_length_fields`).
+    #[test]
+    fn all_vectors_builds_without_panicking() {
+        let vectors = all_vectors();
+        assert!(!vectors.is_empty());
+    }
+
+    #[test]
+    fn modexp_vector_uses_32_byte_length_fields() {
+        let vector = modexp_vector(256);
+        let bytes = 256 / 8;
+        assert_eq!(vector.address, MODEXP);
+        // Three 32-byte length fields, then base/exponent/modulus of `bytes` each.
+        assert_eq!(vector.calldata.len(), 32 * 3 + bytes * 3);
+        assert_eq!(&vector.calldata[0..24], &[0u8; 24]);
+        assert_eq!(&vector.calldata[24..32], &(bytes as u64).to_be_bytes());
+    }
+
+    #[test]
+    fn identity_vector_expected_output_is_its_input() {
+        let vector = identity_vector(32);
+        assert_eq!(vector.expected_output.as_deref(), Some(vector.calldata.as_slice()));
+    }
+
+    #[test]
+    fn dispatcher_bytecode_ends_in_return() {
+        let code = dispatcher_bytecode(IDENTITY);
+        assert_eq!(code.last(), Some(&0xf3)); // RETURN
+        assert!(code.contains(&0xf1)); // CALL
+    }
+}
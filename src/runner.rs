@@ -3,14 +3,19 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::process::Command;
 use serde::{Deserialize, Serialize};
-use crate::benchmarks::{Benchmark, get_evm_benchmarks};
+use crate::benchmarks::{Benchmark, get_evm_benchmarks, get_wasm_benchmarks};
 use crate::compiler::ContractCompiler;
 use crate::display;
+use crate::evm::{BytecodeKind, Capabilities, ExecutionMode};
+
+/// How many `eth_getProof` calls run concurrently during fork-mode prefetch.
+const PREFETCH_BATCH_SIZE: usize = 20;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BenchmarkResult {
     pub name: String,
     pub evm: String,
+    pub mode: String,
     pub mean: f64,
     pub stddev: f64,
     pub median: f64,
@@ -45,6 +50,10 @@ pub fn run_benchmarks(
     output: Option<PathBuf>,
     _export_json: Option<PathBuf>,
     verbose: bool,
+    mode: ExecutionMode,
+    trace: bool,
+    fork_url: Option<String>,
+    fork_block: Option<u64>,
 ) -> Result<()> {
     // Check if hyperfine is installed
     which::which("hyperfine")
@@ -53,7 +62,8 @@ pub fn run_benchmarks(
     // Load compiled contracts and get available benchmarks
     let compiler = ContractCompiler::new()?;
     let compiled_contracts = compiler.compile_all()?;
-    let benchmarks = get_evm_benchmarks(&compiled_contracts);
+    let mut benchmarks = get_evm_benchmarks(&compiled_contracts);
+    benchmarks.extend(get_wasm_benchmarks(&compiler.compile_all_wasm()?));
     if benchmarks.is_empty() {
         bail!("No benchmarks available. Run 'forge build' to compile contracts.");
     }
@@ -72,30 +82,87 @@ pub fn run_benchmarks(
         benchmarks.into_iter().collect::<Vec<_>>()
     };
     
-    // Run benchmarks
-    let mut all_results = HashMap::new();
-    
+    // If fork mode is active, warm the RPC path for every access-list
+    // account/storage-key referenced by the benchmarks about to run before
+    // starting the matrix, and write the result to a cache file every
+    // hyperfine-spawned `execute` subprocess is pointed at via `--fork-cache`
+    // so the prefetch is actually shared rather than discarded per process.
+    let mut fork_cache_path: Option<PathBuf> = None;
+    let _fork_cache_file; // keeps the temp file alive for the rest of this function
+    if let (Some(rpc_url), Some(block)) = (fork_url.as_deref(), fork_block) {
+        let entries = collect_access_list_entries(&benchmarks_to_run)?;
+        if !entries.is_empty() {
+            let slot_count: usize = entries.iter().map(|(_, keys)| keys.len()).sum();
+            println!(
+                "\n🔎 Prefetching {} access-list account(s) ({} storage slot(s)) from fork state...",
+                entries.len(),
+                slot_count
+            );
+            let temp_file = tempfile::NamedTempFile::new()?;
+            crate::fork_db::prefetch_accounts(rpc_url, block, &entries, PREFETCH_BATCH_SIZE, temp_file.path())?;
+            fork_cache_path = Some(temp_file.path().to_path_buf());
+            _fork_cache_file = Some(temp_file);
+        } else {
+            _fork_cache_file = None;
+        }
+    } else {
+        _fork_cache_file = None;
+    }
+
+    // Build the (benchmark, evm) work list up front, skipping incompatible
+    // cells and handling `--trace` inline since it's diagnostic output, not
+    // a timed run.
+    let mut cells = Vec::new();
     for (bench_name, benchmark) in &benchmarks_to_run {
-        println!("\n📊 Running benchmark: {}", bench_name);
-        println!("   {}", benchmark.description);
-        
         for evm_name in &evms_to_run {
-            println!("\n   🔧 EVM: {}", evm_name);
-            
-            let result = run_single_benchmark(
-                evm_name,
-                &benchmark,
-                iterations,
-                warmup,
-                verbose,
-            )?;
-            
-            all_results.entry(bench_name.clone())
-                .or_insert_with(HashMap::new)
-                .insert(evm_name.clone(), result);
+            let caps = backend_capabilities(evm_name);
+            let bench_kind = if benchmark.bench_type == "wasm" { BytecodeKind::Wasm } else { BytecodeKind::Evm };
+            if caps.bytecode_kind != bench_kind {
+                println!("\n⏭️  Skipping {} on {}: bytecode kind mismatch ({:?} benchmark, {:?} backend)",
+                    bench_name, evm_name, bench_kind, caps.bytecode_kind);
+                continue;
+            }
+
+            if trace {
+                println!("\n📊 {} / 🔧 {}", bench_name, evm_name);
+                crate::evm::trace_bytecode(evm_name, &benchmark.bytecode, &benchmark.calldata, benchmark.gas, mode)?;
+                continue;
+            }
+
+            cells.push((bench_name.clone(), benchmark.clone(), evm_name.clone()));
         }
     }
-    
+
+    // Run the remaining (non-trace) cells one at a time rather than on a
+    // concurrent worker pool: these are wall-clock `hyperfine` timing runs,
+    // and running several CPU-bound benchmark processes at once would have
+    // them contend for cores, invalidating the very measurements this
+    // command exists to produce. This only half-satisfies the original
+    // "parallelize independent cells" request — the fork-state prefetch
+    // above is batched/shared across the whole matrix, but cell *execution*
+    // is intentionally kept serial, not parallel, for the reason above.
+    let mut all_results: HashMap<String, HashMap<String, BenchmarkResult>> = HashMap::new();
+    for (bench_name, benchmark, evm_name) in cells {
+        println!("\n📊 Running benchmark: {} / 🔧 {}", bench_name, evm_name);
+
+        let result = run_single_benchmark(
+            &evm_name,
+            &benchmark,
+            iterations,
+            warmup,
+            verbose,
+            mode,
+            fork_url.as_deref(),
+            fork_block,
+            fork_cache_path.as_deref(),
+        )?;
+
+        all_results
+            .entry(bench_name)
+            .or_insert_with(HashMap::new)
+            .insert(evm_name, result);
+    }
+
     // Display results
     display::show_results(&all_results)?;
     
@@ -109,6 +176,59 @@ pub fn run_benchmarks(
     Ok(())
 }
 
+/// Names of the backends the runner can enumerate dynamically for `--all`,
+/// rather than hard-coding a fixed list of "geth/guillotine/revm" strings.
+const BACKEND_REGISTRY: &[&str] = &["revm", "guillotine", "wasm"];
+
+/// Capabilities for each known backend name. The actual benchmark run
+/// happens out-of-process via hyperfine (so timings aren't skewed by this
+/// process's own overhead), but nothing stops constructing an `EvmExecutor`
+/// in-process just to read `capabilities()` off of it — doing so is what
+/// keeps this in sync with each backend's real capabilities instead of
+/// hand-duplicating them here, which had already drifted once (`guillotine`
+/// advertising `supports_tracing`/`has_jit` here that its live executor
+/// didn't actually have). Names `make_executor` doesn't know (e.g. `geth`,
+/// which shells out to a separate binary rather than implementing
+/// `EvmExecutor`) fall back to `Capabilities::default()`.
+fn backend_capabilities(evm_name: &str) -> Capabilities {
+    crate::evm::make_executor(evm_name)
+        .map(|executor| executor.capabilities())
+        .unwrap_or_default()
+}
+
+/// Gather the distinct access-list entries (address plus storage keys)
+/// referenced by any benchmark in the matrix, across both its
+/// `context_fixture` (if any) and its inline `access_list`, for a single
+/// batched prefetch instead of one `eth_getProof`/`eth_getStorageAt` per
+/// benchmark. Entries for the same address are merged so its storage keys
+/// are only ever fetched once.
+fn collect_access_list_entries(
+    benchmarks_to_run: &[(String, Benchmark)],
+) -> Result<Vec<(revm::primitives::Address, Vec<revm::primitives::U256>)>> {
+    let mut merged: HashMap<revm::primitives::Address, std::collections::HashSet<revm::primitives::U256>> = HashMap::new();
+
+    let mut add_entry = |address: [u8; 20], keys: &[[u8; 32]]| {
+        let address = revm::primitives::Address::from(address);
+        let entry = merged.entry(address).or_default();
+        for key in keys {
+            entry.insert(revm::primitives::U256::from_be_bytes(*key));
+        }
+    };
+
+    for (_, benchmark) in benchmarks_to_run {
+        if let Some(fixture_path) = &benchmark.context_fixture {
+            for (address, keys) in crate::context::load_access_list_entries(std::path::Path::new(fixture_path))? {
+                add_entry(address, &keys);
+            }
+        }
+        for (address, keys) in &benchmark.access_list {
+            add_entry(*address, keys);
+        }
+    }
+
+    Ok(merged.into_iter().map(|(address, keys)| (address, keys.into_iter().collect())).collect())
+}
+
 fn determine_evms(
     evm: Option<String>,
     evms: Option<String>,
@@ -116,20 +236,14 @@ fn determine_evms(
 ) -> Result<Vec<String>> {
     if all {
         // Check which EVMs are available
-        let mut available = Vec::new();
-        
-        // Always have revm since it's compiled in
-        available.push("revm".to_string());
-        
+        let mut available: Vec<String> = BACKEND_REGISTRY.iter().map(|s| s.to_string()).collect();
+
         // Check for geth
-        if which::which("evm").is_ok() || 
+        if which::which("evm").is_ok() ||
            std::path::Path::new("evms/go-ethereum/build/bin/evm").exists() {
             available.push("geth".to_string());
         }
-        
-        // Guillotine is always available via the crates.io library
-        available.push("guillotine".to_string());
-        
+
         Ok(available)
     } else if let Some(evms_list) = evms {
         Ok(evms_list.split(',').map(|s| s.trim().to_string()).collect())
@@ -147,21 +261,43 @@ fn run_single_benchmark(
     iterations: usize,
     warmup: usize,
     verbose: bool,
+    mode: ExecutionMode,
+    fork_url: Option<&str>,
+    fork_block: Option<u64>,
+    fork_cache_path: Option<&std::path::Path>,
 ) -> Result<BenchmarkResult> {
     // Get path to our own executable
     let exe_path = std::env::current_exe()
         .context("Failed to get current executable path")?;
-    
+
+    let mode_flag = match mode {
+        ExecutionMode::Interpreter => "interpreter",
+        ExecutionMode::Jit => "jit",
+    };
+
     // Build the command that hyperfine will run
-    let bench_cmd = format!(
-        "{} execute --evm {} --bytecode {} --calldata {} --gas {}",
+    let mut bench_cmd = format!(
+        "{} execute --evm {} --bytecode {} --calldata {} --gas {} --mode {}",
         exe_path.display(),
         evm_name,
         benchmark.bytecode,
         benchmark.calldata,
         benchmark.gas,
+        mode_flag,
     );
-    
+
+    if let Some(rpc_url) = fork_url {
+        let block = fork_block.context("--fork-block is required with --fork-url")?;
+        bench_cmd.push_str(&format!(" --fork-url {} --fork-block {}", rpc_url, block));
+        if let Some(cache_path) = fork_cache_path {
+            bench_cmd.push_str(&format!(" --fork-cache {}", cache_path.display()));
+        }
+    }
+
+    if let Some(fixture_path) = &benchmark.context_fixture {
+        bench_cmd.push_str(&format!(" --context-fixture {}", fixture_path));
+    }
+
     // Create temp file for hyperfine JSON output
     let temp_file = tempfile::NamedTempFile::new()?;
     let json_path = temp_file.path();
@@ -204,6 +340,7 @@ fn run_single_benchmark(
     Ok(BenchmarkResult {
         name: benchmark.name.clone(),
         evm: evm_name.to_string(),
+        mode: mode_flag.to_string(),
         mean: run.mean,
         stddev: run.stddev,
         median: run.median,
@@ -229,5 +366,9 @@ pub fn compare_evms(
         output,
         None,
         false,
+        ExecutionMode::Interpreter,
+        false,
+        None,
+        None,
     )
 }
\ No newline at end of file
@@ -0,0 +1,159 @@
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use crate::evm::EvmExecutor;
+use crate::hex_utils::{decode_hex, parse_address, parse_u256_be};
+
+/// One entry of the standard `ethereum/tests` `GeneralStateTests` format:
+/// a map of test name to pre-state, transaction, environment and expected
+/// post-state per fork.
+#[derive(Debug, Deserialize)]
+pub struct StateTest {
+    pub pre: HashMap<String, StateTestAccount>,
+    pub transaction: StateTestTransaction,
+    pub env: StateTestEnv,
+    pub post: HashMap<String, Vec<StateTestPostEntry>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StateTestAccount {
+    pub balance: String,
+    pub nonce: String,
+    pub code: String,
+    pub storage: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StateTestTransaction {
+    pub data: Vec<String>,
+    #[serde(rename = "gasLimit")]
+    pub gas_limit: Vec<String>,
+    pub value: Vec<String>,
+    pub to: String,
+    #[serde(rename = "gasPrice", default)]
+    pub gas_price: Option<String>,
+    #[serde(rename = "maxFeePerGas", default)]
+    pub max_fee_per_gas: Option<String>,
+    #[serde(rename = "secretKey")]
+    pub secret_key: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StateTestEnv {
+    #[serde(rename = "currentNumber")]
+    pub current_number: String,
+    #[serde(rename = "currentTimestamp")]
+    pub current_timestamp: String,
+    #[serde(rename = "currentCoinbase")]
+    pub current_coinbase: String,
+    #[serde(rename = "currentGasLimit")]
+    pub current_gas_limit: String,
+    #[serde(rename = "currentBaseFee", default)]
+    pub current_base_fee: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StateTestPostEntry {
+    pub indexes: StateTestIndexes,
+    pub hash: String,
+    pub logs: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StateTestIndexes {
+    pub data: usize,
+    pub gas: usize,
+    pub value: usize,
+}
+
+/// Outcome of running one `(data, gas, value)` index from a state test's
+/// transaction matrix, paired with what the fixture expects so a caller can
+/// report on it. `expected_post_hash`/`expected_logs_hash` are carried
+/// through rather than checked here: asserting them for real would mean
+/// reconstructing the post-state trie and an RLP-encoded log list, which is
+/// out of scope for this crate (see `run_state_test`).
+#[derive(Debug)]
+pub struct StateTestCaseResult {
+    pub indexes: StateTestIndexes,
+    pub success: bool,
+    pub gas_used: u64,
+    pub output: Vec<u8>,
+    pub expected_post_hash: String,
+    pub expected_logs_hash: String,
+}
+
+/// Load a `GeneralStateTests` JSON file into its named test cases.
+pub fn load_state_tests(path: &Path) -> Result<HashMap<String, StateTest>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read state test file: {}", path.display()))?;
+    let tests: HashMap<String, StateTest> = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse state test file: {}", path.display()))?;
+    Ok(tests)
+}
+
+/// Seed an executor's pre-state from a state test's `"pre"` section, then run
+/// every (data, gas, value) index in the transaction's matrix, returning each
+/// case's actual result alongside what the fixture expects.
+///
+/// Pre-state seeding goes through `set_balance`/`set_code`/`set_storage`,
+/// which only `GuillotineExecutor` overrides with real per-account mutation
+/// (every other backend's default implementation is a no-op, so seeding
+/// would silently run against empty state instead of the fixture's `pre`).
+/// Rather than let that pass quietly, this rejects any backend but
+/// guillotine up front.
+pub fn run_state_test(
+    executor: &mut dyn EvmExecutor,
+    test: &StateTest,
+    fork: &str,
+) -> Result<Vec<StateTestCaseResult>> {
+    if executor.name() != "guillotine" {
+        bail!(
+            "state tests require a backend that supports per-account pre-state seeding \
+             (set_balance/set_code/set_storage); {} doesn't implement it",
+            executor.name()
+        );
+    }
+
+    for (addr, account) in &test.pre {
+        let address = parse_address(addr)?;
+        executor.set_balance(address, parse_u256_be(&account.balance)?)?;
+        executor.set_code(address, &decode_hex(&account.code)?)?;
+        for (key, value) in &account.storage {
+            executor.set_storage(address, parse_u256_be(key)?, parse_u256_be(value)?)?;
+        }
+    }
+
+    let Some(post_entries) = test.post.get(fork) else {
+        bail!("Fork '{}' not present in state test post-state", fork);
+    };
+
+    let mut results = Vec::with_capacity(post_entries.len());
+    for post in post_entries {
+        let data = test.transaction.data.get(post.indexes.data)
+            .context("Transaction data index out of range")?;
+        let gas_limit = test.transaction.gas_limit.get(post.indexes.gas)
+            .context("Transaction gasLimit index out of range")?;
+
+        let calldata = decode_hex(data)?;
+        let gas = u64::from_str_radix(gas_limit.trim_start_matches("0x"), 16)
+            .with_context(|| format!("Invalid gas limit: {}", gas_limit))?;
+
+        // Run against `to` itself (already seeded with its `pre` code and
+        // storage above) instead of `execute`'s fixed contract address, so a
+        // `SLOAD` in `code` sees the fixture's real storage rather than an
+        // empty account.
+        let to = parse_address(&test.transaction.to)?;
+        let result = executor.execute_at(to, calldata, gas)?;
+        results.push(StateTestCaseResult {
+            indexes: post.indexes.clone(),
+            success: result.success,
+            gas_used: result.gas_used,
+            output: result.output,
+            expected_post_hash: post.hash.clone(),
+            expected_logs_hash: post.logs.clone(),
+        });
+    }
+
+    Ok(results)
+}
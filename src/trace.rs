@@ -0,0 +1,41 @@
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::json;
+use crate::evm::TraceStep;
+
+/// Print `steps` followed by a summary line, one EIP-3155 JSON object per
+/// line, so traces captured from different backends are directly
+/// line-diffable against each other (and against `diff`'s divergence report).
+pub fn print_eip3155(steps: &[TraceStep], output: &[u8], gas_used: u64, pass: bool) -> Result<()> {
+    for step in steps {
+        let mut line = json!({
+            "pc": step.pc,
+            "op": step.op,
+            "gas": format!("0x{:x}", step.gas),
+            "gasCost": format!("0x{:x}", step.gas_cost),
+            "depth": step.depth,
+            "stack": step.stack.iter().map(|w| format!("0x{}", hex::encode(w))).collect::<Vec<_>>(),
+        });
+        if let Some(mem_size) = step.mem_size {
+            line["memSize"] = json!(mem_size);
+        }
+        println!("{}", serde_json::to_string(&line)?);
+    }
+
+    #[derive(Serialize)]
+    struct Summary {
+        output: String,
+        #[serde(rename = "gasUsed")]
+        gas_used: String,
+        pass: bool,
+    }
+
+    let summary = Summary {
+        output: format!("0x{}", hex::encode(output)),
+        gas_used: format!("0x{:x}", gas_used),
+        pass,
+    };
+    println!("{}", serde_json::to_string(&summary)?);
+
+    Ok(())
+}